@@ -0,0 +1,165 @@
+/// A content view allows scrolling of a widget that can
+/// render any part of its content.
+///
+/// The widget only implements [ScrollingContent], the
+/// scrolling is done by [ContentView] and [ViewState].
+///
+/// Unlike [View](crate::View) there is no temporary buffer for
+/// the complete content, the widget only renders the visible part.
+///
+use crate::view::ViewState;
+use crate::ScrollingWidget;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use ratatui::prelude::StatefulWidget;
+use ratatui::style::Style;
+use ratatui::widgets::StatefulWidgetRef;
+use std::cmp::min;
+
+/// Trait for a widget that knows the size of its content and
+/// can render any part of it.
+pub trait ScrollingContent {
+    /// Size of the complete content.
+    fn content_size(&self) -> Size;
+
+    /// Render the visible part of the content.
+    ///
+    /// `visible` is the visible part in content coordinates,
+    /// `area` is the screen area for it. Both have the same size.
+    fn render_content(&self, visible: Rect, area: Rect, buf: &mut Buffer);
+}
+
+/// Wraps a [ScrollingContent] and renders the part
+/// given by the offsets in [ViewState].
+#[derive(Debug, Default, Clone)]
+pub struct ContentView<T> {
+    /// The widget.
+    widget: T,
+    /// Style for any area outside the content.
+    style: Style,
+}
+
+impl<T> ContentView<T> {
+    /// New content view.
+    pub fn new(inner: T) -> Self {
+        Self {
+            widget: inner,
+            style: Default::default(),
+        }
+    }
+
+    /// Style for the empty space outside the content.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<T> StatefulWidgetRef for ContentView<T>
+where
+    T: ScrollingContent,
+{
+    type State = ViewState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(&self.widget, self.style, area, buf, state);
+    }
+}
+
+impl<T> StatefulWidget for ContentView<T>
+where
+    T: ScrollingContent,
+{
+    type State = ViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(&self.widget, self.style, area, buf, state);
+    }
+}
+
+fn render_ref<T>(widget: &T, style: Style, area: Rect, buf: &mut Buffer, state: &mut ViewState)
+where
+    T: ScrollingContent,
+{
+    let size = widget.content_size();
+
+    state.area = area;
+    state.view_area = Rect::new(area.x, area.y, size.width, size.height);
+
+    let h_offset = min(state.h_offset, size.width as usize) as u16;
+    let v_offset = min(state.v_offset, size.height as usize) as u16;
+    let width = min(size.width - h_offset, area.width);
+    let height = min(size.height - v_offset, area.height);
+
+    let visible = Rect::new(h_offset, v_offset, width, height);
+    let content_area = Rect::new(area.x, area.y, width, height);
+
+    widget.render_content(visible, content_area, buf);
+
+    // clear the rest
+    for r in area.y..area.y + area.height {
+        for c in area.x..area.x + area.width {
+            if c >= content_area.x + width || r >= content_area.y + height {
+                buf.get_mut(c, r).reset();
+                buf.get_mut(c, r).set_style(style);
+            }
+        }
+    }
+}
+
+impl<State, T> ScrollingWidget<State> for ContentView<T>
+where
+    T: ScrollingContent,
+{
+    fn need_scroll(&self, area: Rect, _state: &mut State) -> (bool, bool) {
+        let size = self.widget.content_size();
+        (area.width < size.width, area.height < size.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Scrolled, ScrolledState};
+    use std::cell::Cell;
+
+    /// Each row shows its letter. Remembers the requested part.
+    #[derive(Debug)]
+    struct Rows<'a> {
+        visible: &'a Cell<Option<Rect>>,
+    }
+
+    impl ScrollingContent for Rows<'_> {
+        fn content_size(&self) -> Size {
+            Size::new(20, 26)
+        }
+
+        fn render_content(&self, visible: Rect, area: Rect, buf: &mut Buffer) {
+            self.visible.set(Some(visible));
+            for (row, y) in (visible.y..visible.bottom()).zip(area.y..area.bottom()) {
+                let symbol = char::from(b'a' + row as u8).to_string();
+                for x in area.x..area.right() {
+                    buf.get_mut(x, y).set_symbol(&symbol);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn render_visible_part() {
+        let area = Rect::new(0, 0, 10, 6);
+        let mut buf = Buffer::empty(area);
+        let mut state = ScrolledState::<ViewState>::default();
+        state.widget.h_offset = 3;
+        state.widget.v_offset = 5;
+
+        let visible = Cell::new(None);
+        Scrolled::new_content(Rows { visible: &visible }).render(area, &mut buf, &mut state);
+
+        // both scrollbars take one cell.
+        assert_eq!(state.view_area, Rect::new(0, 0, 9, 5));
+        assert_eq!(visible.get(), Some(Rect::new(3, 5, 9, 5)));
+        assert_eq!(buf.get(0, 0).symbol(), "f");
+        assert_eq!(buf.get(8, 4).symbol(), "j");
+    }
+}
@@ -0,0 +1,233 @@
+/// Input for the scroll handling, independent of the event source.
+///
+/// The event handlers translate their events to ScrollInput and
+/// feed it to [ScrolledState::handle_input](crate::ScrolledState::handle_input).
+/// This can be used to test the scroll handling without creating
+/// crossterm events, or to connect another event source.
+///
+use rat_event::ct_event;
+use ratatui::layout::Position;
+
+/// Scroll relevant input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollInput {
+    /// Left mouse button pressed.
+    Press(Position),
+    /// Mouse drag with the left button.
+    Drag(Position),
+    /// Mouse drag with the left button and SHIFT held.
+    ShiftDrag(Position),
+    /// Middle mouse button or CTRL+left button pressed.
+    /// Starts panning the content.
+    PanStart(Position),
+    /// Mouse drag with the middle button or CTRL+left button.
+    Pan(Position),
+    /// Mouse moved without a button.
+    Moved(Position),
+    /// Mouse wheel up.
+    ScrollUp(Position),
+    /// Mouse wheel down.
+    ScrollDown(Position),
+    /// CTRL+mouse wheel up.
+    ScrollPageUp(Position),
+    /// CTRL+mouse wheel down.
+    ScrollPageDown(Position),
+    /// Horizontal mouse wheel to the left.
+    ScrollLeft(Position),
+    /// Horizontal mouse wheel to the right.
+    ScrollRight(Position),
+    /// Combined wheel movement in both directions, as some
+    /// trackpads report it.
+    ///
+    /// h and v count wheel steps, negative values scroll
+    /// left/up, positive values scroll right/down.
+    /// Both directions are applied with one event, and the
+    /// result is a single Changed.
+    ScrollBy { pos: Position, h: i16, v: i16 },
+}
+
+impl ScrollInput {
+    /// Translate a crossterm event.
+    ///
+    /// SHIFT+wheel scrolls horizontally. If alt_scroll is set
+    /// ALT+wheel does the same. The middle button and CTRL+left
+    /// button pan the content. A horizontal wheel reported by
+    /// the terminal maps to ScrollLeft/ScrollRight.
+    ///
+    /// Crossterm reports one direction per event, so this
+    /// never creates a ScrollBy.
+    pub fn from_crossterm(
+        event: &crossterm::event::Event,
+        alt_scroll: bool,
+    ) -> Option<ScrollInput> {
+        match event {
+            ct_event!(mouse down Left for column, row) => {
+                Some(ScrollInput::Press(Position::new(*column, *row)))
+            }
+            ct_event!(mouse drag Left for column, row) => {
+                Some(ScrollInput::Drag(Position::new(*column, *row)))
+            }
+            ct_event!(mouse drag SHIFT-Left for column, row) => {
+                Some(ScrollInput::ShiftDrag(Position::new(*column, *row)))
+            }
+            ct_event!(mouse down Middle for column, row)
+            | ct_event!(mouse down CONTROL-Left for column, row) => {
+                Some(ScrollInput::PanStart(Position::new(*column, *row)))
+            }
+            ct_event!(mouse drag Middle for column, row)
+            | ct_event!(mouse drag CONTROL-Left for column, row) => {
+                Some(ScrollInput::Pan(Position::new(*column, *row)))
+            }
+            ct_event!(mouse moved for column, row) => {
+                Some(ScrollInput::Moved(Position::new(*column, *row)))
+            }
+            ct_event!(scroll down for column, row) => {
+                Some(ScrollInput::ScrollDown(Position::new(*column, *row)))
+            }
+            ct_event!(scroll up for column, row) => {
+                Some(ScrollInput::ScrollUp(Position::new(*column, *row)))
+            }
+            ct_event!(scroll SHIFT down for column, row) => {
+                Some(ScrollInput::ScrollRight(Position::new(*column, *row)))
+            }
+            ct_event!(scroll SHIFT up for column, row) => {
+                Some(ScrollInput::ScrollLeft(Position::new(*column, *row)))
+            }
+            ct_event!(scroll CONTROL down for column, row) => {
+                Some(ScrollInput::ScrollPageDown(Position::new(*column, *row)))
+            }
+            ct_event!(scroll CONTROL up for column, row) => {
+                Some(ScrollInput::ScrollPageUp(Position::new(*column, *row)))
+            }
+            ct_event!(scroll ALT down for column, row) if alt_scroll => {
+                Some(ScrollInput::ScrollRight(Position::new(*column, *row)))
+            }
+            ct_event!(scroll ALT up for column, row) if alt_scroll => {
+                Some(ScrollInput::ScrollLeft(Position::new(*column, *row)))
+            }
+            ct_event!(scroll left for column, row) => {
+                Some(ScrollInput::ScrollLeft(Position::new(*column, *row)))
+            }
+            ct_event!(scroll right for column, row) => {
+                Some(ScrollInput::ScrollRight(Position::new(*column, *row)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Event types that can be translated to [ScrollInput].
+///
+/// The event handling of [ScrolledState](crate::ScrolledState) for
+/// MouseOnly and Inner is generic over this trait. Implement it
+/// for the event type of another backend to use the scroll handling
+/// there. The keyboard handling with FocusKeys is still bound to
+/// crossterm.
+pub trait ScrollEvent {
+    /// Translate to ScrollInput. Returns None if the event
+    /// is not relevant for scrolling.
+    ///
+    /// If alt_scroll is set ALT+wheel scrolls horizontally.
+    fn scroll_input(&self, alt_scroll: bool) -> Option<ScrollInput>;
+}
+
+impl ScrollEvent for crossterm::event::Event {
+    fn scroll_input(&self, alt_scroll: bool) -> Option<ScrollInput> {
+        ScrollInput::from_crossterm(self, alt_scroll)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+    fn mouse(kind: MouseEventKind, modifiers: KeyModifiers) -> Event {
+        Event::Mouse(MouseEvent {
+            kind,
+            column: 3,
+            row: 4,
+            modifiers,
+        })
+    }
+
+    #[test]
+    fn from_crossterm_mapping() {
+        use KeyModifiers as M;
+        use MouseButton as B;
+        use MouseEventKind as K;
+
+        let pos = Position::new(3, 4);
+        let cases = [
+            (K::Down(B::Left), M::NONE, Some(ScrollInput::Press(pos))),
+            (K::Drag(B::Left), M::NONE, Some(ScrollInput::Drag(pos))),
+            (
+                K::Drag(B::Left),
+                M::SHIFT,
+                Some(ScrollInput::ShiftDrag(pos)),
+            ),
+            (
+                K::Down(B::Middle),
+                M::NONE,
+                Some(ScrollInput::PanStart(pos)),
+            ),
+            (
+                K::Down(B::Left),
+                M::CONTROL,
+                Some(ScrollInput::PanStart(pos)),
+            ),
+            (K::Drag(B::Middle), M::NONE, Some(ScrollInput::Pan(pos))),
+            (K::Drag(B::Left), M::CONTROL, Some(ScrollInput::Pan(pos))),
+            (K::Moved, M::NONE, Some(ScrollInput::Moved(pos))),
+            (K::ScrollDown, M::NONE, Some(ScrollInput::ScrollDown(pos))),
+            (K::ScrollUp, M::NONE, Some(ScrollInput::ScrollUp(pos))),
+            (K::ScrollDown, M::SHIFT, Some(ScrollInput::ScrollRight(pos))),
+            (K::ScrollUp, M::SHIFT, Some(ScrollInput::ScrollLeft(pos))),
+            (
+                K::ScrollDown,
+                M::CONTROL,
+                Some(ScrollInput::ScrollPageDown(pos)),
+            ),
+            (
+                K::ScrollUp,
+                M::CONTROL,
+                Some(ScrollInput::ScrollPageUp(pos)),
+            ),
+            (K::ScrollRight, M::NONE, Some(ScrollInput::ScrollRight(pos))),
+            (K::ScrollLeft, M::NONE, Some(ScrollInput::ScrollLeft(pos))),
+            (K::Up(B::Left), M::NONE, None),
+            (K::Down(B::Right), M::NONE, None),
+        ];
+        for (kind, modifiers, expected) in cases {
+            assert_eq!(
+                ScrollInput::from_crossterm(&mouse(kind, modifiers), false),
+                expected,
+                "{:?} {:?}",
+                kind,
+                modifiers
+            );
+        }
+    }
+
+    #[test]
+    fn from_crossterm_alt_scroll() {
+        let pos = Position::new(3, 4);
+        let down = mouse(MouseEventKind::ScrollDown, KeyModifiers::ALT);
+        let up = mouse(MouseEventKind::ScrollUp, KeyModifiers::ALT);
+
+        assert_eq!(
+            ScrollInput::from_crossterm(&down, true),
+            Some(ScrollInput::ScrollRight(pos))
+        );
+        assert_eq!(
+            ScrollInput::from_crossterm(&up, true),
+            Some(ScrollInput::ScrollLeft(pos))
+        );
+        assert_eq!(ScrollInput::from_crossterm(&down, false), None);
+        assert_eq!(ScrollInput::from_crossterm(&up, false), None);
+
+        // the same through the trait.
+        assert_eq!(down.scroll_input(true), Some(ScrollInput::ScrollRight(pos)));
+        assert_eq!(down.scroll_input(false), None);
+    }
+}
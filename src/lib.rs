@@ -1,7 +1,10 @@
 #![doc = include_str!("../readme.md")]
 
+mod content;
 mod inner;
+mod input;
 mod scrolled;
+pub mod symbols;
 mod util;
 mod view;
 mod viewport;
@@ -9,11 +12,13 @@ mod viewport;
 use ratatui::layout::Rect;
 use std::cmp::{max, min};
 
+pub use content::{ContentView, ScrollingContent};
+pub use input::{ScrollEvent, ScrollInput};
 pub use scrolled::{
-    HScrollPosition, Inner, ScrollbarPolicy, Scrolled, ScrolledState, ScrolledStyle,
-    VScrollPosition,
+    HScrollPosition, Inner, ScrollbarInteraction, ScrollbarPolicy, ScrollbarShown, ScrollbarType,
+    Scrolled, ScrolledState, ScrolledStyle, VScrollPosition,
 };
-pub use view::{View, ViewState};
+pub use view::{VerticalAlignment, View, ViewMouse, ViewState};
 pub use viewport::{Viewport, ViewportState};
 
 /// Trait for the widget struct of a scrollable widget.
@@ -109,6 +114,18 @@ pub trait ScrollingState {
             self.horizontal_max_offset(),
         ))
     }
+
+    /// Limit both offsets to the current max_offset.
+    ///
+    /// Useful after restoring offsets or when the area changed.
+    /// The widget returns true if any offset changed.
+    fn clamp_offsets(&mut self) -> bool {
+        let v_changed =
+            self.set_vertical_offset(min(self.vertical_offset(), self.vertical_max_offset()));
+        let h_changed =
+            self.set_horizontal_offset(min(self.horizontal_offset(), self.horizontal_max_offset()));
+        v_changed || h_changed
+    }
 }
 
 // /// A widget that can differentiate between these states can use this as a flag.
@@ -143,6 +160,14 @@ pub mod event {
         Changed,
         /// Outcome of the inner widget.
         Inner(R),
+        /// The event tried to scroll up/left, but the offset is
+        /// already at the start. No repaint necessary.
+        AtStart,
+        /// The event tried to scroll down/right, but the offset is
+        /// already at the end. No repaint necessary.
+        ///
+        /// Useful for loading more data when the user keeps scrolling.
+        AtEnd,
     }
 
     impl<T> From<ScrollOutcome<T>> for Outcome {
@@ -152,6 +177,72 @@ pub mod event {
                 ScrollOutcome::Unchanged => Outcome::Unchanged,
                 ScrollOutcome::Changed => Outcome::Changed,
                 ScrollOutcome::Inner(_) => Outcome::Changed,
+                ScrollOutcome::AtStart => Outcome::Unchanged,
+                ScrollOutcome::AtEnd => Outcome::Unchanged,
+            }
+        }
+    }
+
+    impl<R> ScrollOutcome<R> {
+        /// Wrap the outcome of the inner widget.
+        pub fn from_inner(inner: R) -> Self {
+            ScrollOutcome::Inner(inner)
+        }
+
+        /// Map the outcome of the inner widget.
+        /// All other variants are passed through.
+        pub fn map_inner<U>(self, f: impl FnOnce(R) -> U) -> ScrollOutcome<U> {
+            match self {
+                ScrollOutcome::NotUsed => ScrollOutcome::NotUsed,
+                ScrollOutcome::Unchanged => ScrollOutcome::Unchanged,
+                ScrollOutcome::Changed => ScrollOutcome::Changed,
+                ScrollOutcome::Inner(i) => ScrollOutcome::Inner(f(i)),
+                ScrollOutcome::AtStart => ScrollOutcome::AtStart,
+                ScrollOutcome::AtEnd => ScrollOutcome::AtEnd,
+            }
+        }
+
+        /// Does this outcome require a repaint?
+        ///
+        /// * NotUsed: false
+        /// * Unchanged: false
+        /// * Changed: true
+        /// * Inner: true, the same as the conversion to [Outcome].
+        /// * AtStart, AtEnd: false
+        ///
+        /// Whether the event was consumed is answered by
+        /// [ConsumedEvent::is_consumed], which is what flow!() uses.
+        pub fn should_redraw(&self) -> bool {
+            match self {
+                ScrollOutcome::NotUsed => false,
+                ScrollOutcome::Unchanged => false,
+                ScrollOutcome::Changed => true,
+                ScrollOutcome::Inner(_) => true,
+                ScrollOutcome::AtStart => false,
+                ScrollOutcome::AtEnd => false,
+            }
+        }
+    }
+
+    impl<R> ScrollOutcome<R>
+    where
+        R: ConsumedEvent,
+    {
+        /// Combine the outcomes of two event handlers that got
+        /// the same event.
+        ///
+        /// An outcome that used the event wins over one that didn't.
+        /// If both used it, an outcome that needs a repaint wins.
+        /// Otherwise self is kept.
+        pub fn merge(self, other: ScrollOutcome<R>) -> ScrollOutcome<R> {
+            if !self.is_consumed() {
+                other
+            } else if !other.is_consumed() {
+                self
+            } else if self.should_redraw() || !other.should_redraw() {
+                self
+            } else {
+                other
             }
         }
     }
@@ -165,10 +256,14 @@ pub mod event {
                     ScrollOutcome::NotUsed => ScrollOutcome::NotUsed,
                     ScrollOutcome::Unchanged => ScrollOutcome::Unchanged,
                     ScrollOutcome::Changed => ScrollOutcome::Changed,
+                    ScrollOutcome::AtStart => ScrollOutcome::AtStart,
+                    ScrollOutcome::AtEnd => ScrollOutcome::AtEnd,
                 },
                 ScrollOutcome::NotUsed => ScrollOutcome::NotUsed,
                 ScrollOutcome::Unchanged => ScrollOutcome::Unchanged,
                 ScrollOutcome::Changed => ScrollOutcome::Changed,
+                ScrollOutcome::AtStart => ScrollOutcome::AtStart,
+                ScrollOutcome::AtEnd => ScrollOutcome::AtEnd,
             }
         }
     }
@@ -183,14 +278,20 @@ pub mod event {
                         ScrollOutcome::NotUsed => ScrollOutcome::NotUsed,
                         ScrollOutcome::Unchanged => ScrollOutcome::Unchanged,
                         ScrollOutcome::Changed => ScrollOutcome::Changed,
+                        ScrollOutcome::AtStart => ScrollOutcome::AtStart,
+                        ScrollOutcome::AtEnd => ScrollOutcome::AtEnd,
                     },
                     ScrollOutcome::NotUsed => ScrollOutcome::NotUsed,
                     ScrollOutcome::Unchanged => ScrollOutcome::Unchanged,
                     ScrollOutcome::Changed => ScrollOutcome::Changed,
+                    ScrollOutcome::AtStart => ScrollOutcome::AtStart,
+                    ScrollOutcome::AtEnd => ScrollOutcome::AtEnd,
                 },
                 ScrollOutcome::NotUsed => ScrollOutcome::NotUsed,
                 ScrollOutcome::Unchanged => ScrollOutcome::Unchanged,
                 ScrollOutcome::Changed => ScrollOutcome::Changed,
+                ScrollOutcome::AtStart => ScrollOutcome::AtStart,
+                ScrollOutcome::AtEnd => ScrollOutcome::AtEnd,
             }
         }
     }
@@ -205,12 +306,94 @@ pub mod event {
                 ScrollOutcome::NotUsed => false,
                 ScrollOutcome::Unchanged => true,
                 ScrollOutcome::Changed => true,
+                ScrollOutcome::AtStart => true,
+                ScrollOutcome::AtEnd => true,
             }
         }
     }
 }
 
 mod _private {
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct NonExhaustive;
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::event::{ConsumedEvent, Outcome, ScrollOutcome};
+
+    #[test]
+    fn outcome_classification() {
+        // (outcome, is_consumed, should_redraw)
+        let cases = [
+            (ScrollOutcome::NotUsed, false, false),
+            (ScrollOutcome::Unchanged, true, false),
+            (ScrollOutcome::Changed, true, true),
+            (ScrollOutcome::AtStart, true, false),
+            (ScrollOutcome::AtEnd, true, false),
+            (ScrollOutcome::Inner(Outcome::NotUsed), false, true),
+            (ScrollOutcome::Inner(Outcome::Unchanged), true, true),
+            (ScrollOutcome::Inner(Outcome::Changed), true, true),
+        ];
+        for (outcome, consumed, redraw) in cases {
+            assert_eq!(outcome.is_consumed(), consumed, "{:?}", outcome);
+            assert_eq!(outcome.should_redraw(), redraw, "{:?}", outcome);
+        }
+    }
+
+    #[test]
+    fn outcome_merge() {
+        use ScrollOutcome as S;
+
+        // (self, other, merged)
+        let cases = [
+            (S::NotUsed, S::NotUsed, S::NotUsed),
+            (S::NotUsed, S::Unchanged, S::Unchanged),
+            (S::NotUsed, S::AtEnd, S::AtEnd),
+            (S::Unchanged, S::NotUsed, S::Unchanged),
+            (S::AtStart, S::NotUsed, S::AtStart),
+            (S::Unchanged, S::Changed, S::Changed),
+            (S::Changed, S::Unchanged, S::Changed),
+            (S::AtStart, S::AtEnd, S::AtStart),
+            (S::AtEnd, S::Changed, S::Changed),
+            (S::Changed, S::AtEnd, S::Changed),
+            (
+                S::Unchanged,
+                S::Inner(Outcome::Changed),
+                S::Inner(Outcome::Changed),
+            ),
+            (S::Inner(Outcome::NotUsed), S::Unchanged, S::Unchanged),
+            (
+                S::Inner(Outcome::Unchanged),
+                S::NotUsed,
+                S::Inner(Outcome::Unchanged),
+            ),
+            (S::Changed, S::Inner(Outcome::Changed), S::Changed),
+        ];
+        for (a, b, merged) in cases {
+            assert_eq!(a.merge(b), merged, "{:?} {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn outcome_map_inner() {
+        let cases = [
+            (ScrollOutcome::NotUsed, ScrollOutcome::NotUsed),
+            (ScrollOutcome::Unchanged, ScrollOutcome::Unchanged),
+            (ScrollOutcome::Changed, ScrollOutcome::Changed),
+            (ScrollOutcome::AtStart, ScrollOutcome::AtStart),
+            (ScrollOutcome::AtEnd, ScrollOutcome::AtEnd),
+            (
+                ScrollOutcome::from_inner(1),
+                ScrollOutcome::Inner("1".to_string()),
+            ),
+        ];
+        for (outcome, mapped) in cases {
+            assert_eq!(outcome.map_inner(|v: i32| v.to_string()), mapped);
+        }
+        assert_eq!(
+            ScrollOutcome::from_inner(Outcome::Changed),
+            ScrollOutcome::Inner(Outcome::Changed)
+        );
+    }
+}
@@ -5,24 +5,29 @@
 /// There is a second trait [ScrollingState] necessary for the state.
 ///
 use crate::_private::NonExhaustive;
+use crate::content::{ContentView, ScrollingContent};
 use crate::event::ScrollOutcome;
 use crate::event::{FocusKeys, HandleEvent, MouseOnly};
 use crate::inner::{InnerStatefulOwned, InnerStatefulRef, InnerWidget};
-use crate::view::View;
+use crate::input::{ScrollEvent, ScrollInput};
+use crate::view::{VerticalAlignment, View};
 use crate::viewport::Viewport;
 use crate::{ScrollingState, ScrollingWidget};
 #[allow(unused_imports)]
 use log::debug;
 use rat_event::{ct_event, ConsumedEvent};
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Position, Rect, Size};
+use ratatui::layout::{Alignment, Position, Rect, Size};
 use ratatui::prelude::{BlockExt, Style};
 use ratatui::symbols::scrollbar::Set;
 use ratatui::widgets::{
     Block, Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget, StatefulWidgetRef,
     Widget, WidgetRef,
 };
-use std::cmp::min;
+use std::borrow::Cow;
+use std::cmp::{max, min};
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
 
 /// A wrapper widget that scrolls it's content.
 #[derive(Debug, Default, Clone)]
@@ -40,28 +45,85 @@ struct ScrolledImpl<'a> {
     v_scroll_policy: ScrollbarPolicy,
     h_scroll_position: HScrollPosition,
     v_scroll_position: VScrollPosition,
+    config: ScrolledConfig,
+    start_margin: u16,
+    end_margin: u16,
+    track_length: Option<u16>,
+    track_alignment: Alignment,
+    empty_scrollbar: ScrollbarType,
+    cache_need_scroll: bool,
+    position_label: bool,
 
     block: Option<Block<'a>>,
+    corner_widget: Option<CornerWidget<'a>>,
 
-    thumb_symbol: Option<&'a str>,
+    thumb_symbol: Option<Cow<'a, str>>,
     thumb_style: Option<Style>,
-    track_symbol: Option<&'a str>,
+    hover_style: Option<Style>,
+    track_symbol: Option<Cow<'a, str>>,
     track_style: Option<Style>,
-    begin_symbol: Option<&'a str>,
+    filled_track_style: Option<Style>,
+    begin_symbol: Option<Cow<'a, str>>,
     begin_style: Option<Style>,
-    end_symbol: Option<&'a str>,
+    end_symbol: Option<Cow<'a, str>>,
     end_style: Option<Style>,
+    corner_symbol: Option<Cow<'a, str>>,
+    corner_style: Option<Style>,
+    v_no_symbol: Option<Cow<'a, str>>,
+    h_no_symbol: Option<Cow<'a, str>>,
+    v_symbols: Option<Set>,
+    h_symbols: Option<Set>,
+    respect_border: bool,
+    marker: Option<usize>,
+    markers: &'a [usize],
+    marker_symbol: Option<Cow<'a, str>>,
+    marker_style: Option<Style>,
 }
 
-#[derive(Debug, Clone)]
+/// Settings of the Scrolled widget that are needed for the
+/// event-handling. They are copied to the state with each render.
+#[derive(Debug, Default, Clone, Copy)]
+struct ScrolledConfig {
+    scrollbar_interaction: ScrollbarInteraction,
+    min_thumb_len: u16,
+    no_alt_scroll: bool,
+    pass_through_at_bounds: bool,
+    pan: bool,
+    ctrl_wheel_page: bool,
+    invert_wheel: bool,
+    cross_axis_drag: bool,
+    no_arrows: bool,
+}
+
+/// Widget for the corner between the scrollbars.
+#[derive(Clone, Copy)]
+struct CornerWidget<'a>(&'a dyn WidgetRef);
+
+impl<'a> Debug for CornerWidget<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CornerWidget")
+    }
+}
+
+/// Combined styles and symbols for the scrollbars.
+///
+/// The symbols are `Cow`s, so they can be loaded at runtime,
+/// e.g. from a theme file.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ScrolledStyle {
     pub thumb_style: Option<Style>,
-    pub track_symbol: Option<&'static str>,
+    pub hover_style: Option<Style>,
+    pub track_symbol: Option<Cow<'static, str>>,
     pub track_style: Option<Style>,
-    pub begin_symbol: Option<&'static str>,
+    pub filled_track_style: Option<Style>,
+    pub begin_symbol: Option<Cow<'static, str>>,
     pub begin_style: Option<Style>,
-    pub end_symbol: Option<&'static str>,
+    pub end_symbol: Option<Cow<'static, str>>,
     pub end_style: Option<Style>,
+    pub corner_symbol: Option<Cow<'static, str>>,
+    pub corner_style: Option<Style>,
+    pub v_no_symbol: Option<Cow<'static, str>>,
+    pub h_no_symbol: Option<Cow<'static, str>>,
 
     pub non_exhaustive: NonExhaustive,
 }
@@ -80,15 +142,61 @@ pub struct ScrolledState<WidgetState> {
     pub h_scrollbar_area: Option<Rect>,
     /// Scrollbar area.
     pub v_scrollbar_area: Option<Rect>,
+    /// Corner between the scrollbars, if both are shown.
+    pub corner_area: Option<Rect>,
+    /// Why the (horizontal, vertical) scrollbars are shown.
+    pub scrollbar_reason: (ScrollbarShown, ScrollbarShown),
 
     /// Allow overscroll by n items.
     pub v_overscroll: usize,
     /// Allow overscroll by n items.
     pub h_overscroll: usize,
 
+    /// Vertical scroll step for the mouse wheel. If this is None
+    /// the widgets vertical_scroll() is used.
+    pub v_wheel_scroll_by: Option<usize>,
+    /// Horizontal scroll step for the mouse wheel. If this is None
+    /// the widgets horizontal_scroll() is used.
+    pub h_wheel_scroll_by: Option<usize>,
+    /// Scroll step for the mouse wheel as a fraction of the page.
+    /// Only used if there is no fixed step.
+    pub wheel_scroll_fraction: Option<f32>,
+
+    /// Cached (horizontal, vertical) scrollbars for the given area,
+    /// as derived from [ScrollingWidget::need_scroll].
+    /// Only used if [Scrolled::cache_need_scroll] is set.
+    pub need_scroll_cache: Option<(Rect, (bool, bool))>,
+
+    /// Running animation as (target offset, step per tick).
+    pub v_animation: Option<(usize, usize)>,
+    /// Running animation as (target offset, step per tick).
+    pub h_animation: Option<(usize, usize)>,
+    /// Running flick as (velocity per tick, fractional rest).
+    pub v_flick: Option<(f32, f32)>,
+    /// Running flick as (velocity per tick, fractional rest).
+    pub h_flick: Option<(f32, f32)>,
+    /// Velocity is multiplied with this for every tick.
+    pub flick_friction: f32,
+
+    /// Wheel events are handled inside this area instead
+    /// of the area. See [ScrolledState::set_scroll_capture_area].
+    pub scroll_capture_area: Option<Rect>,
+
+    /// Last mouse position inside the area.
+    /// Used for the hover style.
+    pub mouse_pos: Option<Position>,
+
     /// mouse action in progress
     pub v_drag: bool,
     pub h_drag: bool,
+    /// Distance between the mouse and the start of the thumb
+    /// when the drag started.
+    pub drag_grab: u16,
+    /// Last mouse position while panning.
+    pub pan_pos: Option<Position>,
+
+    /// Settings of the last render.
+    config: ScrolledConfig,
 
     pub non_exhaustive: NonExhaustive,
 }
@@ -103,6 +211,34 @@ pub enum ScrollbarPolicy {
     Never,
 }
 
+/// Why a scrollbar is shown.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollbarShown {
+    /// The scrollbar is not shown.
+    #[default]
+    Hidden,
+    /// Shown due to ScrollbarPolicy::Always.
+    Policy,
+    /// Shown because the content needs scrolling.
+    Needed,
+}
+
+/// Rendering of a scrollbar when there is nothing to scroll.
+///
+/// The [ScrollbarPolicy] decides whether the space for the scrollbar
+/// is reserved. With ScrollbarPolicy::Always the scrollbar is there
+/// even if max_offset is 0, and this decides what is drawn in its place.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollbarType {
+    /// Draw the arrows and a thumb that fills the track.
+    Show,
+    /// Only fill the area with the track style.
+    #[default]
+    Minimal,
+    /// Don't draw anything. The widget below must fill the area.
+    NoRender,
+}
+
 /// Position of the vertical scrollbar.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum VScrollPosition {
@@ -119,6 +255,17 @@ pub enum HScrollPosition {
     Bottom,
 }
 
+/// What happens with a click on the scrollbar track.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollbarInteraction {
+    /// Jump to the clicked position.
+    #[default]
+    Jump,
+    /// Scroll by one page in the direction of the click.
+    /// A click on the thumb starts dragging without jumping.
+    Page,
+}
+
 impl<'a, T> Scrolled<'a, T> {
     /// New scrolled widget.
     pub fn new(inner: T) -> Self {
@@ -129,12 +276,20 @@ impl<'a, T> Scrolled<'a, T> {
     }
 
     /// Allow overscrolling the max_offset by n.
+    ///
+    /// The inner state has the last word about its offset.
+    /// [View] and [Viewport] limit the offset to their max_offset,
+    /// so there is no overscroll with them.
     pub fn vertical_overscroll(mut self, n: usize) -> Self {
         self.scrolled.v_overscroll = n;
         self
     }
 
     /// Allow overscrolling the max_offset by n.
+    ///
+    /// The inner state has the last word about its offset.
+    /// [View] and [Viewport] limit the offset to their max_offset,
+    /// so there is no overscroll with them.
     pub fn horizontal_overscroll(mut self, n: usize) -> Self {
         self.scrolled.h_overscroll = n;
         self
@@ -164,6 +319,162 @@ impl<'a, T> Scrolled<'a, T> {
         self
     }
 
+    /// Show the begin/end arrows of the scrollbars.
+    ///
+    /// Without arrows the track uses the full length of the
+    /// scrollbar. The mapping of clicks and drags follows.
+    /// Default is on.
+    pub fn arrows(mut self, arrows: bool) -> Self {
+        self.scrolled.config.no_arrows = !arrows;
+        self
+    }
+
+    /// What is drawn for a scrollbar when there is nothing to scroll.
+    ///
+    /// This only matters if the scrollbar is shown anyway,
+    /// see [ScrollbarPolicy::Always].
+    pub fn empty_scrollbar(mut self, scrollbar_type: ScrollbarType) -> Self {
+        self.scrolled.empty_scrollbar = scrollbar_type;
+        self
+    }
+
+    /// Behaviour for a click on the scrollbar track.
+    pub fn scrollbar_interaction(mut self, interaction: ScrollbarInteraction) -> Self {
+        self.scrolled.config.scrollbar_interaction = interaction;
+        self
+    }
+
+    /// Minimum length of the scrollbar thumb.
+    ///
+    /// With a large max_offset the thumb shrinks to a single cell
+    /// and is hard to grab. This enlarges the thumb, and the
+    /// mapping of clicks and drags to an offset follows.
+    pub fn min_thumb_len(mut self, len: u16) -> Self {
+        self.scrolled.config.min_thumb_len = len;
+        self
+    }
+
+    /// Leave a gap before the start of the scrollbars.
+    ///
+    /// This is the top of the vertical and the left side
+    /// of the horizontal scrollbar.
+    pub fn start_margin(mut self, margin: u16) -> Self {
+        self.scrolled.start_margin = margin;
+        self
+    }
+
+    /// Leave a gap after the end of the scrollbars.
+    ///
+    /// This is the bottom of the vertical and the right side
+    /// of the horizontal scrollbar.
+    pub fn end_margin(mut self, margin: u16) -> Self {
+        self.scrolled.end_margin = margin;
+        self
+    }
+
+    /// Limit the track of the scrollbars to this length.
+    /// The arrows are added to this.
+    ///
+    /// The scrollbar is placed in the available space with
+    /// [track_alignment](Scrolled::track_alignment). Clicks and
+    /// drags use the shorter scrollbar. Useful for a small
+    /// position indicator that shares its row with other content.
+    pub fn track_length(mut self, track_length: Option<u16>) -> Self {
+        self.scrolled.track_length = track_length;
+        self
+    }
+
+    /// Placement of a scrollbar that is shorter than the available
+    /// space due to [track_length](Scrolled::track_length).
+    ///
+    /// Alignment::Left is the top of the vertical scrollbar,
+    /// Alignment::Right the bottom.
+    pub fn track_alignment(mut self, align: Alignment) -> Self {
+        self.scrolled.track_alignment = align;
+        self
+    }
+
+    /// Horizontal scrolling uses SHIFT+wheel, and ALT+wheel as
+    /// a fallback. This switches the ALT bindings on/off,
+    /// if ALT is needed elsewhere.
+    ///
+    /// Default is on.
+    pub fn alt_scroll(mut self, alt_scroll: bool) -> Self {
+        self.scrolled.config.no_alt_scroll = !alt_scroll;
+        self
+    }
+
+    /// A wheel event that can't scroll any further returns NotUsed
+    /// instead of AtStart/AtEnd. The event can then scroll a
+    /// surrounding widget, which is useful for nested scrolling.
+    /// The same goes for Up/Down and PageUp/PageDown with FocusKeys.
+    ///
+    /// Default is off.
+    pub fn pass_through_at_bounds(mut self, pass_through: bool) -> Self {
+        self.scrolled.config.pass_through_at_bounds = pass_through;
+        self
+    }
+
+    /// Drag the content with the middle button or CTRL+left button,
+    /// like a hand tool. Useful for map or diagram like content
+    /// in a View.
+    ///
+    /// The inner widget gets the events first, and can still
+    /// use them. Default is off.
+    pub fn enable_pan(mut self, pan: bool) -> Self {
+        self.scrolled.config.pan = pan;
+        self
+    }
+
+    /// CTRL+wheel scrolls by a page instead of the wheel step.
+    ///
+    /// Default is off, and CTRL+wheel is not used.
+    pub fn ctrl_wheel_page(mut self, page: bool) -> Self {
+        self.scrolled.config.ctrl_wheel_page = page;
+        self
+    }
+
+    /// Invert the direction of the mouse wheel, for natural scrolling.
+    ///
+    /// This applies to the horizontal SHIFT/ALT+wheel too.
+    /// Dragging and clicking the scrollbars is not affected.
+    pub fn invert_wheel(mut self, invert: bool) -> Self {
+        self.scrolled.config.invert_wheel = invert;
+        self
+    }
+
+    /// Dragging the vertical scrollbar with SHIFT held scrolls
+    /// horizontally instead. The position on the vertical track
+    /// is mapped to the horizontal offset.
+    ///
+    /// For cramped layouts where only the vertical scrollbar is
+    /// shown. Default is off.
+    pub fn allow_cross_axis_drag(mut self, allow: bool) -> Self {
+        self.scrolled.config.cross_axis_drag = allow;
+        self
+    }
+
+    /// Cache the result of [ScrollingWidget::need_scroll].
+    ///
+    /// For widgets where measuring the content is expensive.
+    /// need_scroll is called again only if the area changes,
+    /// or after [ScrolledState::invalidate]. It's the job of the
+    /// application to call invalidate whenever the content changes.
+    pub fn cache_need_scroll(mut self, cache: bool) -> Self {
+        self.scrolled.cache_need_scroll = cache;
+        self
+    }
+
+    /// Show the current offset as "offset/max_offset" on the
+    /// scrollbar track, centered on the thumb.
+    ///
+    /// The label is only drawn if the track is long enough.
+    /// On the vertical scrollbar it is drawn top to bottom.
+    pub fn position_label(mut self, show: bool) -> Self {
+        self.scrolled.position_label = show;
+        self
+    }
+
     /// Block around the scrolled widget. The scrollbars are drawn
     /// as part of the block.
     ///
@@ -175,20 +486,34 @@ impl<'a, T> Scrolled<'a, T> {
         self
     }
 
+    /// Widget that is rendered in the corner between the scrollbars.
+    ///
+    /// This is only rendered if both scrollbars are shown.
+    pub fn corner_widget(mut self, widget: &'a dyn WidgetRef) -> Self {
+        self.scrolled.corner_widget = Some(CornerWidget(widget));
+        self
+    }
+
     pub fn styles(mut self, styles: ScrolledStyle) -> Self {
         self.scrolled.thumb_style = styles.thumb_style;
+        self.scrolled.hover_style = styles.hover_style;
         self.scrolled.track_symbol = styles.track_symbol;
         self.scrolled.track_style = styles.track_style;
+        self.scrolled.filled_track_style = styles.filled_track_style;
         self.scrolled.begin_symbol = styles.begin_symbol;
         self.scrolled.begin_style = styles.begin_style;
         self.scrolled.end_symbol = styles.end_symbol;
         self.scrolled.end_style = styles.end_style;
+        self.scrolled.corner_symbol = styles.corner_symbol;
+        self.scrolled.corner_style = styles.corner_style;
+        self.scrolled.v_no_symbol = styles.v_no_symbol;
+        self.scrolled.h_no_symbol = styles.h_no_symbol;
         self
     }
 
     /// Symbol for the Scrollbar.
     pub fn thumb_symbol(mut self, thumb_symbol: &'a str) -> Self {
-        self.scrolled.thumb_symbol = Some(thumb_symbol);
+        self.scrolled.thumb_symbol = Some(Cow::Borrowed(thumb_symbol));
         self
     }
 
@@ -198,9 +523,18 @@ impl<'a, T> Scrolled<'a, T> {
         self
     }
 
+    /// Style for the thumb while the mouse hovers over it.
+    ///
+    /// This needs the mouse move events. They are part of the
+    /// normal event handling, but the terminal must report them.
+    pub fn hover_style<S: Into<Style>>(mut self, hover_style: S) -> Self {
+        self.scrolled.hover_style = Some(hover_style.into());
+        self
+    }
+
     /// Symbol for the Scrollbar.
     pub fn track_symbol(mut self, track_symbol: Option<&'a str>) -> Self {
-        self.scrolled.track_symbol = track_symbol;
+        self.scrolled.track_symbol = track_symbol.map(Cow::Borrowed);
         self
     }
 
@@ -210,9 +544,18 @@ impl<'a, T> Scrolled<'a, T> {
         self
     }
 
+    /// Style for the part of the track before the thumb.
+    ///
+    /// The rest of the track keeps the track_style, which
+    /// gives a progress-bar like look.
+    pub fn filled_track_style<S: Into<Style>>(mut self, filled_track_style: S) -> Self {
+        self.scrolled.filled_track_style = Some(filled_track_style.into());
+        self
+    }
+
     /// Symbol for the Scrollbar.
     pub fn begin_symbol(mut self, begin_symbol: Option<&'a str>) -> Self {
-        self.scrolled.begin_symbol = begin_symbol;
+        self.scrolled.begin_symbol = begin_symbol.map(Cow::Borrowed);
         self
     }
 
@@ -224,7 +567,7 @@ impl<'a, T> Scrolled<'a, T> {
 
     /// Symbol for the Scrollbar.
     pub fn end_symbol(mut self, end_symbol: Option<&'a str>) -> Self {
-        self.scrolled.end_symbol = end_symbol;
+        self.scrolled.end_symbol = end_symbol.map(Cow::Borrowed);
         self
     }
 
@@ -234,21 +577,124 @@ impl<'a, T> Scrolled<'a, T> {
         self
     }
 
+    /// Symbol for the corner between the scrollbars.
+    /// Default is a blank. With a block the corner is part of
+    /// the border and is left alone by default.
+    pub fn corner_symbol(mut self, corner_symbol: &'a str) -> Self {
+        self.scrolled.corner_symbol = Some(Cow::Borrowed(corner_symbol));
+        self
+    }
+
+    /// Style for the corner between the scrollbars.
+    /// Defaults to the track_style, but not with a block.
+    pub fn corner_style<S: Into<Style>>(mut self, corner_style: S) -> Self {
+        self.scrolled.corner_style = Some(corner_style.into());
+        self
+    }
+
+    /// Symbol that fills both scrollbars with [ScrollbarType::Minimal].
+    /// Default is to fill only with the track style.
+    ///
+    /// There are presets in [symbols](crate::symbols).
+    pub fn no_symbol(mut self, no_symbol: &'a str) -> Self {
+        self.scrolled.v_no_symbol = Some(Cow::Borrowed(no_symbol));
+        self.scrolled.h_no_symbol = Some(Cow::Borrowed(no_symbol));
+        self
+    }
+
+    /// Symbol that fills the vertical scrollbar with [ScrollbarType::Minimal].
+    pub fn v_no_symbol(mut self, no_symbol: &'a str) -> Self {
+        self.scrolled.v_no_symbol = Some(Cow::Borrowed(no_symbol));
+        self
+    }
+
+    /// Symbol that fills the horizontal scrollbar with [ScrollbarType::Minimal].
+    pub fn h_no_symbol(mut self, no_symbol: &'a str) -> Self {
+        self.scrolled.h_no_symbol = Some(Cow::Borrowed(no_symbol));
+        self
+    }
+
+    /// Mark the position of this item on the vertical scrollbar,
+    /// e.g. the current search match.
+    ///
+    /// The item index is scaled like the thumb. The marker is
+    /// drawn over the track and the thumb.
+    pub fn marker(mut self, marker: Option<usize>) -> Self {
+        self.scrolled.marker = marker;
+        self
+    }
+
+    /// Mark the positions of all these items on the vertical
+    /// scrollbar, e.g. all search matches. Markers are only drawn
+    /// on the vertical scrollbar.
+    ///
+    /// Consecutive items that map to the same cell are drawn once,
+    /// so sorted markers are cheaper. Unsorted markers are drawn
+    /// correctly, but a cell may be drawn more than once. Items
+    /// beyond the end of the content are skipped.
+    ///
+    /// This costs O(markers) for each render.
+    pub fn markers(mut self, markers: &'a [usize]) -> Self {
+        self.scrolled.markers = markers;
+        self
+    }
+
+    /// Symbol for the marker. Default keeps the symbol of
+    /// the scrollbar and only sets the style.
+    pub fn marker_symbol(mut self, marker_symbol: &'a str) -> Self {
+        self.scrolled.marker_symbol = Some(Cow::Borrowed(marker_symbol));
+        self
+    }
+
+    /// Style for the marker. Defaults to the thumb_style.
+    pub fn marker_style<S: Into<Style>>(mut self, marker_style: S) -> Self {
+        self.scrolled.marker_style = Some(marker_style.into());
+        self
+    }
+
+    /// With [ScrollbarType::Minimal] leave the first and last cell
+    /// of the scrollbars alone. Use this if they coincide with the
+    /// corners of a border.
+    ///
+    /// Default is off.
+    pub fn respect_border(mut self, respect_border: bool) -> Self {
+        self.scrolled.respect_border = respect_border;
+        self
+    }
+
     /// Set all Scrollbar symbols.
     pub fn symbols(mut self, symbols: Set) -> Self {
-        self.scrolled.thumb_symbol = Some(symbols.thumb);
+        self.scrolled.thumb_symbol = Some(Cow::Borrowed(symbols.thumb));
         if self.scrolled.track_symbol.is_some() {
-            self.scrolled.track_symbol = Some(symbols.track);
+            self.scrolled.track_symbol = Some(Cow::Borrowed(symbols.track));
         }
         if self.scrolled.begin_symbol.is_some() {
-            self.scrolled.begin_symbol = Some(symbols.begin);
+            self.scrolled.begin_symbol = Some(Cow::Borrowed(symbols.begin));
         }
         if self.scrolled.end_symbol.is_some() {
-            self.scrolled.end_symbol = Some(symbols.end);
+            self.scrolled.end_symbol = Some(Cow::Borrowed(symbols.end));
         }
         self
     }
 
+    /// Set all symbols of the vertical scrollbar.
+    ///
+    /// This overrides the symbols set for both scrollbars.
+    /// There are presets in [symbols](crate::symbols).
+    pub fn v_symbols(mut self, symbols: Set) -> Self {
+        self.scrolled.v_symbols = Some(symbols);
+        self
+    }
+
+    /// Set all symbols of the horizontal scrollbar.
+    ///
+    /// This overrides the symbols set for both scrollbars.
+    /// There are presets in [symbols](crate::symbols).
+    pub fn h_symbols(mut self, symbols: Set) -> Self {
+        self.scrolled.h_symbols = Some(symbols);
+        self
+    }
+
     /// Set a style for all Scrollbar styles.
     pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
         let style = style.into();
@@ -260,6 +706,28 @@ impl<'a, T> Scrolled<'a, T> {
     }
 }
 
+impl<'a, W> Scrolled<'a, ContentView<W>>
+where
+    W: ScrollingContent,
+{
+    /// Create a `Scrolled<ContentView<W>>` widget for widgets that
+    /// can render any part of their content.
+    ///
+    /// See [ScrollingContent].
+    pub fn new_content(inner: W) -> Scrolled<'a, ContentView<W>> {
+        Self {
+            widget: ContentView::new(inner),
+            scrolled: Default::default(),
+        }
+    }
+
+    /// Style for the empty space outside the content.
+    pub fn view_style(mut self, style: Style) -> Self {
+        self.widget = self.widget.style(style);
+        self
+    }
+}
+
 impl<'a, W> Scrolled<'a, View<W>>
 where
     W: Widget,
@@ -268,7 +736,9 @@ where
     /// scrolling behaviour.
     ///
     /// You need to set a [view_size](Scrolled::view_size()) for the
-    /// area the inner widget shall receive.
+    /// area the inner widget shall receive, or calculate it from
+    /// the area with [view_size_fn](Scrolled::view_size_fn()).
+    /// Without either the view is empty and a warning is logged.
     ///
     /// See [Viewport] too.
     pub fn new_view(inner: W) -> Scrolled<'a, View<W>> {
@@ -284,11 +754,26 @@ where
         self
     }
 
+    /// Calculate the size for the inner widget from the area.
+    pub fn view_size_fn(
+        mut self,
+        view_size_fn: impl Fn(Rect) -> Size + Send + Sync + 'static,
+    ) -> Self {
+        self.widget = self.widget.view_size_fn(view_size_fn);
+        self
+    }
+
     /// Style for the empty space outside the rendered buffer.
     pub fn view_style(mut self, style: Style) -> Self {
         self.widget = self.widget.style(style);
         self
     }
+
+    /// Alignment of the content if it is smaller than the area.
+    pub fn content_align(mut self, h_align: Alignment, v_align: VerticalAlignment) -> Self {
+        self.widget = self.widget.content_align(h_align, v_align);
+        self
+    }
 }
 
 impl<'a, W> Scrolled<'a, Viewport<W>>
@@ -299,7 +784,9 @@ where
     /// scrolling behaviour.
     ///
     /// You need to set a [view_size](Scrolled::view_size()) for the
-    /// area the inner widget shall receive.
+    /// area the inner widget shall receive, or calculate it from
+    /// the area with [view_size_fn](Scrolled::view_size_fn()).
+    /// Without either the view is empty and a warning is logged.
     ///
     /// See [Viewport] too.
     pub fn new_viewport(inner: W) -> Scrolled<'a, Viewport<W>> {
@@ -315,11 +802,26 @@ where
         self
     }
 
+    /// Calculate the size for the inner widget from the area.
+    pub fn view_size_fn(
+        mut self,
+        view_size_fn: impl Fn(Rect) -> Size + Send + Sync + 'static,
+    ) -> Self {
+        self.widget = self.widget.view_size_fn(view_size_fn);
+        self
+    }
+
     /// Style for the empty space outside the rendered buffer.
     pub fn view_style(mut self, style: Style) -> Self {
         self.widget = self.widget.style(style);
         self
     }
+
+    /// Alignment of the content if it is smaller than the area.
+    pub fn content_align(mut self, h_align: Alignment, v_align: VerticalAlignment) -> Self {
+        self.widget = self.widget.content_align(h_align, v_align);
+        self
+    }
 }
 
 impl<'a, W> StatefulWidgetRef for Scrolled<'a, W>
@@ -359,73 +861,140 @@ fn render_ref<W, S>(
 ) where
     S: ScrollingState,
 {
-    // reduced area for the widget to account for possible scrollbars.
-    let view_area = if scrolled.block.is_some() {
-        // block should already account for the scrollbars.
-        scrolled.block.inner_if_some(area)
+    let (has_hscroll, has_vscroll) = if scrolled.cache_need_scroll {
+        match state.need_scroll_cache {
+            Some((cache_area, show_scroll)) if cache_area == area => show_scroll,
+            _ => {
+                let show_scroll = show_scrollbars(scrolled, &inner, area, &mut state.widget);
+                state.need_scroll_cache = Some((area, show_scroll));
+                show_scroll
+            }
+        }
     } else {
-        let w = if scrolled.h_scroll_policy != ScrollbarPolicy::Never {
-            area.width.saturating_sub(1)
-        } else {
-            area.width
-        };
-        let h = if scrolled.v_scroll_policy != ScrollbarPolicy::Never {
-            area.height.saturating_sub(1)
-        } else {
-            area.height
-        };
-        Rect::new(area.x, area.y, w, h)
+        show_scrollbars(scrolled, &inner, area, &mut state.widget)
     };
 
-    let scroll_param = inner.need_scroll(view_area, &mut state.widget);
-
     state.area = area;
+    state.scrollbar_reason = (
+        scrollbar_reason(has_hscroll, scrolled.h_scroll_policy),
+        scrollbar_reason(has_vscroll, scrolled.v_scroll_policy),
+    );
     state.v_overscroll = scrolled.v_overscroll;
     state.h_overscroll = scrolled.h_overscroll;
-
-    let has_hscroll = scrolled.h_scroll_policy.apply(scroll_param.0);
-    let has_vscroll = scrolled.v_scroll_policy.apply(scroll_param.1);
+    state.config = scrolled.config;
+    let arrow_len = state.arrow_len();
 
     debug!("scroll {:?} {:?}", has_hscroll, has_vscroll);
 
     // Calculate the areas for the scrollbars and the view-area.
-    // If there is a block set, assume there is a right and a bottom border too.
-    // Currently, there is no way to know it. Overwriting part of the content is
-    // ok in this case.
+    // If there is a block set, assume there is a border on the sides
+    // of the scrollbars too. Currently, there is no way to know it.
+    // Overwriting part of the content is ok in this case.
     if has_vscroll {
-        let mut vscrollbar_area = area.columns().last().expect("scroll");
+        let mut vscrollbar_area = match scrolled.v_scroll_position {
+            VScrollPosition::Left => area.columns().next().expect("scroll"),
+            VScrollPosition::Right => area.columns().last().expect("scroll"),
+        };
         if scrolled.block.is_some() {
             vscrollbar_area.y += 1;
             vscrollbar_area.height = vscrollbar_area.height.saturating_sub(1);
         }
         if has_hscroll {
             debug!("double scroll");
-            vscrollbar_area.height = vscrollbar_area.height.saturating_sub(1);
+            match scrolled.h_scroll_position {
+                HScrollPosition::Top => {
+                    // with a block the top border is already excluded.
+                    if scrolled.block.is_none() {
+                        vscrollbar_area.y += 1;
+                        vscrollbar_area.height = vscrollbar_area.height.saturating_sub(1);
+                    }
+                }
+                HScrollPosition::Bottom => {
+                    vscrollbar_area.height = vscrollbar_area.height.saturating_sub(1);
+                }
+            }
         }
+        let start_margin = min(scrolled.start_margin, vscrollbar_area.height);
+        vscrollbar_area.y += start_margin;
+        vscrollbar_area.height = vscrollbar_area
+            .height
+            .saturating_sub(start_margin + scrolled.end_margin);
+        (vscrollbar_area.y, vscrollbar_area.height) = limit_track_length(
+            vscrollbar_area.y,
+            vscrollbar_area.height,
+            scrolled.track_length,
+            arrow_len,
+            scrolled.track_alignment,
+        );
         state.v_scrollbar_area = Some(vscrollbar_area);
+    } else {
+        state.v_scrollbar_area = None;
     }
 
     if has_hscroll {
-        let mut hscrollbar_area = area.rows().last().expect("scroll");
+        let mut hscrollbar_area = match scrolled.h_scroll_position {
+            HScrollPosition::Top => area.rows().next().expect("scroll"),
+            HScrollPosition::Bottom => area.rows().last().expect("scroll"),
+        };
         if scrolled.block.is_some() {
             hscrollbar_area.x += 1;
             hscrollbar_area.width = hscrollbar_area.width.saturating_sub(1);
         }
         if has_vscroll {
-            hscrollbar_area.width = hscrollbar_area.width.saturating_sub(1);
+            match scrolled.v_scroll_position {
+                VScrollPosition::Left => {
+                    // with a block the left border is already excluded.
+                    if scrolled.block.is_none() {
+                        hscrollbar_area.x += 1;
+                        hscrollbar_area.width = hscrollbar_area.width.saturating_sub(1);
+                    }
+                }
+                VScrollPosition::Right => {
+                    hscrollbar_area.width = hscrollbar_area.width.saturating_sub(1);
+                }
+            }
         }
+        let start_margin = min(scrolled.start_margin, hscrollbar_area.width);
+        hscrollbar_area.x += start_margin;
+        hscrollbar_area.width = hscrollbar_area
+            .width
+            .saturating_sub(start_margin + scrolled.end_margin);
+        (hscrollbar_area.x, hscrollbar_area.width) = limit_track_length(
+            hscrollbar_area.x,
+            hscrollbar_area.width,
+            scrolled.track_length,
+            arrow_len,
+            scrolled.track_alignment,
+        );
         state.h_scrollbar_area = Some(hscrollbar_area);
+    } else {
+        state.h_scrollbar_area = None;
     }
 
+    state.corner_area = if has_vscroll && has_hscroll {
+        match (state.v_scrollbar_area, state.h_scrollbar_area) {
+            (Some(v), Some(h)) => Some(Rect::new(v.x, h.y, 1, 1)),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
     // calculate actual view area
     if let Some(block) = scrolled.block.as_ref() {
         state.view_area = block.inner(area);
     } else {
         state.view_area = area;
         if has_vscroll {
+            if scrolled.v_scroll_position == VScrollPosition::Left {
+                state.view_area.x += 1;
+            }
             state.view_area.width = state.view_area.width.saturating_sub(1);
         }
         if has_hscroll {
+            if scrolled.h_scroll_position == HScrollPosition::Top {
+                state.view_area.y += 1;
+            }
             state.view_area.height = state.view_area.height.saturating_sub(1);
         }
     }
@@ -436,18 +1005,24 @@ fn render_ref<W, S>(
 
     if let Some(vscrollbar_area) = state.v_scrollbar_area {
         let mut vscroll = Scrollbar::new(scrolled.v_scroll_position.orientation());
-        if let Some(thumb_symbol) = scrolled.thumb_symbol {
+        if let Some(thumb_symbol) = scrolled.thumb_symbol.as_deref() {
             vscroll = vscroll.thumb_symbol(thumb_symbol);
         }
-        if let Some(track_symbol) = scrolled.track_symbol {
+        if let Some(track_symbol) = scrolled.track_symbol.as_deref() {
             vscroll = vscroll.track_symbol(Some(track_symbol));
         }
-        if let Some(begin_symbol) = scrolled.begin_symbol {
+        if let Some(begin_symbol) = scrolled.begin_symbol.as_deref() {
             vscroll = vscroll.begin_symbol(Some(begin_symbol));
         }
-        if let Some(end_symbol) = scrolled.end_symbol {
+        if let Some(end_symbol) = scrolled.end_symbol.as_deref() {
             vscroll = vscroll.end_symbol(Some(end_symbol));
         }
+        if let Some(v_symbols) = scrolled.v_symbols {
+            vscroll = vscroll.symbols(v_symbols);
+        }
+        if scrolled.config.no_arrows {
+            vscroll = vscroll.begin_symbol(None).end_symbol(None);
+        }
         if let Some(thumb_style) = scrolled.thumb_style {
             vscroll = vscroll.thumb_style(thumb_style);
         }
@@ -463,35 +1038,143 @@ fn render_ref<W, S>(
 
         let max_offset = state.widget.vertical_max_offset();
         let offset = state.widget.vertical_offset();
-        let view_len = state.widget.vertical_page();
+        let view_len = scrollbar_page_len(
+            max_offset,
+            state.widget.vertical_page(),
+            vscrollbar_area.height.saturating_sub(2 * arrow_len),
+            scrolled.config.min_thumb_len,
+        );
 
         if max_offset == 0 {
             // when max_offset is 0, Scrollbar doesn't do anything.
-            if let Some(track_style) = scrolled.track_style {
-                buf.set_style(vscrollbar_area, track_style);
+            match scrolled.empty_scrollbar {
+                ScrollbarType::Show => {
+                    let track_len = vscrollbar_area.height.saturating_sub(2 * arrow_len);
+                    let mut vscroll_state = ScrollbarState::new(1)
+                        .position(0)
+                        .viewport_content_length(track_len as usize);
+                    vscroll.render(vscrollbar_area, buf, &mut vscroll_state);
+                }
+                ScrollbarType::Minimal => {
+                    let fill_area = if scrolled.respect_border {
+                        // keep the first and last cell for the border.
+                        Rect::new(
+                            vscrollbar_area.x,
+                            vscrollbar_area.y + 1,
+                            vscrollbar_area.width,
+                            vscrollbar_area.height.saturating_sub(2),
+                        )
+                    } else {
+                        vscrollbar_area
+                    };
+                    if let Some(no_symbol) = scrolled.v_no_symbol.as_deref() {
+                        for y in fill_area.top()..fill_area.bottom() {
+                            for x in fill_area.left()..fill_area.right() {
+                                buf.get_mut(x, y).set_symbol(no_symbol);
+                            }
+                        }
+                    }
+                    if let Some(track_style) = scrolled.track_style {
+                        buf.set_style(fill_area, track_style);
+                    }
+                }
+                ScrollbarType::NoRender => {}
             }
         } else {
             let mut vscroll_state = ScrollbarState::new(max_offset)
                 .position(offset)
                 .viewport_content_length(view_len);
             vscroll.render(vscrollbar_area, buf, &mut vscroll_state);
+
+            if let Some(filled_track_style) = scrolled.filled_track_style {
+                if let Some((thumb_start, _)) = state.vertical_thumb_bounds() {
+                    // the track starts after the begin arrow.
+                    let filled_area = Rect::new(
+                        vscrollbar_area.x,
+                        vscrollbar_area.y + arrow_len,
+                        1,
+                        thumb_start.saturating_sub(arrow_len),
+                    );
+                    buf.set_style(
+                        filled_area.intersection(vscrollbar_area),
+                        filled_track_style,
+                    );
+                }
+            }
+
+            if let Some(hover_style) = scrolled.hover_style {
+                if let Some((thumb_start, thumb_len)) = state.vertical_thumb_hover() {
+                    let thumb_area = Rect::new(
+                        vscrollbar_area.x,
+                        vscrollbar_area.y + thumb_start,
+                        1,
+                        thumb_len,
+                    );
+                    buf.set_style(thumb_area.intersection(vscrollbar_area), hover_style);
+                }
+            }
+
+            let track_len = vscrollbar_area.height.saturating_sub(2 * arrow_len);
+            let mut last_pos = None;
+            for marker in scrolled.marker.iter().chain(scrolled.markers.iter()) {
+                let Some(marker_pos) = marker_pos(*marker, max_offset, view_len, track_len) else {
+                    continue;
+                };
+                // sorted markers often hit the same cell.
+                if last_pos == Some(marker_pos) {
+                    continue;
+                }
+                last_pos = Some(marker_pos);
+
+                let cell = buf.get_mut(
+                    vscrollbar_area.x,
+                    vscrollbar_area.y + arrow_len + marker_pos,
+                );
+                if let Some(marker_symbol) = scrolled.marker_symbol.as_deref() {
+                    cell.set_symbol(marker_symbol);
+                }
+                if let Some(marker_style) = scrolled.marker_style.or(scrolled.thumb_style) {
+                    cell.set_style(marker_style);
+                }
+            }
+
+            if scrolled.position_label {
+                if let Some((thumb_start, thumb_len)) = state.vertical_thumb_bounds() {
+                    let label = format!("{}/{}", offset, max_offset);
+                    render_position_label(
+                        &label,
+                        vscrollbar_area,
+                        arrow_len,
+                        true,
+                        thumb_start,
+                        thumb_len,
+                        buf,
+                    );
+                }
+            }
         }
     }
 
     if let Some(hscrollbar_area) = state.h_scrollbar_area {
         let mut hscroll = Scrollbar::new(scrolled.h_scroll_position.orientation());
-        if let Some(thumb_symbol) = scrolled.thumb_symbol {
+        if let Some(thumb_symbol) = scrolled.thumb_symbol.as_deref() {
             hscroll = hscroll.thumb_symbol(thumb_symbol);
         }
-        if let Some(track_symbol) = scrolled.track_symbol {
+        if let Some(track_symbol) = scrolled.track_symbol.as_deref() {
             hscroll = hscroll.track_symbol(Some(track_symbol));
         }
-        if let Some(begin_symbol) = scrolled.begin_symbol {
+        if let Some(begin_symbol) = scrolled.begin_symbol.as_deref() {
             hscroll = hscroll.begin_symbol(Some(begin_symbol));
         }
-        if let Some(end_symbol) = scrolled.end_symbol {
+        if let Some(end_symbol) = scrolled.end_symbol.as_deref() {
             hscroll = hscroll.end_symbol(Some(end_symbol));
         }
+        if let Some(h_symbols) = scrolled.h_symbols {
+            hscroll = hscroll.symbols(h_symbols);
+        }
+        if scrolled.config.no_arrows {
+            hscroll = hscroll.begin_symbol(None).end_symbol(None);
+        }
         if let Some(thumb_style) = scrolled.thumb_style {
             hscroll = hscroll.thumb_style(thumb_style);
         }
@@ -507,12 +1190,47 @@ fn render_ref<W, S>(
 
         let max_offset = state.widget.horizontal_max_offset();
         let offset = state.widget.horizontal_offset();
-        let view_len = state.widget.horizontal_page();
+        let view_len = scrollbar_page_len(
+            max_offset,
+            state.widget.horizontal_page(),
+            hscrollbar_area.width.saturating_sub(2 * arrow_len),
+            scrolled.config.min_thumb_len,
+        );
 
         if max_offset == 0 {
             // when max_offset is 0, Scrollbar doesn't do anything.
-            if let Some(track_style) = scrolled.track_style {
-                buf.set_style(hscrollbar_area, track_style);
+            match scrolled.empty_scrollbar {
+                ScrollbarType::Show => {
+                    let track_len = hscrollbar_area.width.saturating_sub(2 * arrow_len);
+                    let mut hscroll_state = ScrollbarState::new(1)
+                        .position(0)
+                        .viewport_content_length(track_len as usize);
+                    hscroll.render(hscrollbar_area, buf, &mut hscroll_state);
+                }
+                ScrollbarType::Minimal => {
+                    let fill_area = if scrolled.respect_border {
+                        // keep the first and last cell for the border.
+                        Rect::new(
+                            hscrollbar_area.x + 1,
+                            hscrollbar_area.y,
+                            hscrollbar_area.width.saturating_sub(2),
+                            hscrollbar_area.height,
+                        )
+                    } else {
+                        hscrollbar_area
+                    };
+                    if let Some(no_symbol) = scrolled.h_no_symbol.as_deref() {
+                        for y in fill_area.top()..fill_area.bottom() {
+                            for x in fill_area.left()..fill_area.right() {
+                                buf.get_mut(x, y).set_symbol(no_symbol);
+                            }
+                        }
+                    }
+                    if let Some(track_style) = scrolled.track_style {
+                        buf.set_style(fill_area, track_style);
+                    }
+                }
+                ScrollbarType::NoRender => {}
             }
         } else {
             let mut hscroll_state = ScrollbarState::new(max_offset)
@@ -520,26 +1238,216 @@ fn render_ref<W, S>(
                 .viewport_content_length(view_len);
 
             hscroll.render(hscrollbar_area, buf, &mut hscroll_state);
+
+            if let Some(filled_track_style) = scrolled.filled_track_style {
+                if let Some((thumb_start, _)) = state.horizontal_thumb_bounds() {
+                    // the track starts after the begin arrow.
+                    let filled_area = Rect::new(
+                        hscrollbar_area.x + arrow_len,
+                        hscrollbar_area.y,
+                        thumb_start.saturating_sub(arrow_len),
+                        1,
+                    );
+                    buf.set_style(
+                        filled_area.intersection(hscrollbar_area),
+                        filled_track_style,
+                    );
+                }
+            }
+
+            if let Some(hover_style) = scrolled.hover_style {
+                if let Some((thumb_start, thumb_len)) = state.horizontal_thumb_hover() {
+                    let thumb_area = Rect::new(
+                        hscrollbar_area.x + thumb_start,
+                        hscrollbar_area.y,
+                        thumb_len,
+                        1,
+                    );
+                    buf.set_style(thumb_area.intersection(hscrollbar_area), hover_style);
+                }
+            }
+
+            if scrolled.position_label {
+                if let Some((thumb_start, thumb_len)) = state.horizontal_thumb_bounds() {
+                    let label = format!("{}/{}", offset, max_offset);
+                    render_position_label(
+                        &label,
+                        hscrollbar_area,
+                        arrow_len,
+                        false,
+                        thumb_start,
+                        thumb_len,
+                        buf,
+                    );
+                }
+            }
         }
     }
-}
 
-impl Default for ScrolledStyle {
-    fn default() -> Self {
-        Self {
-            thumb_style: None,
-            track_symbol: None,
-            track_style: None,
-            begin_symbol: None,
-            begin_style: None,
-            end_symbol: None,
-            end_style: None,
-            non_exhaustive: NonExhaustive,
+    if let Some(corner_area) = state.corner_area {
+        if let Some(corner_widget) = scrolled.corner_widget {
+            corner_widget.0.render_ref(corner_area, buf);
+        } else if scrolled.block.is_some() {
+            // the corner is part of the border, only change it
+            // if explicitly asked for.
+            let cell = buf.get_mut(corner_area.x, corner_area.y);
+            if let Some(corner_symbol) = scrolled.corner_symbol.as_deref() {
+                cell.set_symbol(corner_symbol);
+            }
+            if let Some(corner_style) = scrolled.corner_style {
+                cell.set_style(corner_style);
+            }
+        } else {
+            let cell = buf.get_mut(corner_area.x, corner_area.y);
+            cell.reset();
+            cell.set_symbol(scrolled.corner_symbol.as_deref().unwrap_or(" "));
+            if let Some(corner_style) = scrolled.corner_style.or(scrolled.track_style) {
+                cell.set_style(corner_style);
+            }
         }
     }
 }
 
-impl ScrollbarPolicy {
+/// Why a scrollbar is shown. Anything but Always
+/// only shows the scrollbar if it is needed.
+fn scrollbar_reason(shown: bool, policy: ScrollbarPolicy) -> ScrollbarShown {
+    if !shown {
+        ScrollbarShown::Hidden
+    } else if policy == ScrollbarPolicy::Always {
+        ScrollbarShown::Policy
+    } else {
+        ScrollbarShown::Needed
+    }
+}
+
+/// Decide which scrollbars are shown as (horizontal, vertical).
+///
+/// Without a block the scrollbars take space from the view area,
+/// which in turn can make another scrollbar necessary. This
+/// starts with no scrollbars except ScrollbarPolicy::Always and
+/// adds scrollbars until need_scroll is satisfied. Content that
+/// fits the area exactly gets no scrollbars.
+fn show_scrollbars<S>(
+    scrolled: &ScrolledImpl<'_>,
+    inner: &impl ScrollingWidget<S>,
+    area: Rect,
+    state: &mut S,
+) -> (bool, bool) {
+    if scrolled.block.is_some() {
+        // block should already account for the scrollbars.
+        let view_area = scrolled.block.inner_if_some(area);
+        let scroll_param = inner.need_scroll(view_area, state);
+        return (
+            scrolled.h_scroll_policy.apply(scroll_param.0),
+            scrolled.v_scroll_policy.apply(scroll_param.1),
+        );
+    }
+
+    let mut has_hscroll = scrolled.h_scroll_policy.apply(false);
+    let mut has_vscroll = scrolled.v_scroll_policy.apply(false);
+    // each round can only add a scrollbar, so this ends after at most 3 rounds.
+    loop {
+        let mut view_area = area;
+        if has_vscroll {
+            view_area.width = view_area.width.saturating_sub(1);
+        }
+        if has_hscroll {
+            view_area.height = view_area.height.saturating_sub(1);
+        }
+
+        let scroll_param = inner.need_scroll(view_area, state);
+        let need_hscroll = has_hscroll || scrolled.h_scroll_policy.apply(scroll_param.0);
+        let need_vscroll = has_vscroll || scrolled.v_scroll_policy.apply(scroll_param.1);
+
+        if need_hscroll == has_hscroll && need_vscroll == has_vscroll {
+            break;
+        }
+        has_hscroll = need_hscroll;
+        has_vscroll = need_vscroll;
+    }
+
+    (has_hscroll, has_vscroll)
+}
+
+/// Draws the label on the track, centered on the thumb.
+///
+/// Keeps the styles of the scrollbar. Nothing is drawn
+/// if the track is too short for the label.
+fn render_position_label(
+    label: &str,
+    scrollbar_area: Rect,
+    arrow_len: u16,
+    vertical: bool,
+    thumb_start: u16,
+    thumb_len: u16,
+    buf: &mut Buffer,
+) {
+    let area_len = if vertical {
+        scrollbar_area.height
+    } else {
+        scrollbar_area.width
+    };
+    // the track is between the arrows.
+    let track_len = area_len.saturating_sub(2 * arrow_len);
+    let label_len = label.chars().count();
+    if label_len == 0 || label_len > track_len as usize {
+        return;
+    }
+    let label_len = label_len as u16;
+
+    let center = thumb_start + thumb_len / 2;
+    let start = center
+        .saturating_sub(label_len / 2)
+        .clamp(arrow_len, arrow_len + track_len - label_len);
+
+    for (i, c) in label.chars().enumerate() {
+        let pos = start + i as u16;
+        let (x, y) = if vertical {
+            (scrollbar_area.x, scrollbar_area.y + pos)
+        } else {
+            (scrollbar_area.x + pos, scrollbar_area.y)
+        };
+        buf.get_mut(x, y).set_char(c);
+    }
+}
+
+impl Default for ScrolledStyle {
+    fn default() -> Self {
+        Self {
+            thumb_style: None,
+            hover_style: None,
+            track_symbol: None,
+            track_style: None,
+            filled_track_style: None,
+            begin_symbol: None,
+            begin_style: None,
+            end_symbol: None,
+            end_style: None,
+            corner_symbol: None,
+            corner_style: None,
+            v_no_symbol: None,
+            h_no_symbol: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl ScrolledStyle {
+    /// Use the same style for thumb, track, begin and end.
+    ///
+    /// This is the same as [Scrolled::style].
+    pub fn uniform(style: Style) -> Self {
+        Self {
+            thumb_style: Some(style),
+            track_style: Some(style),
+            begin_style: Some(style),
+            end_style: Some(style),
+            ..Default::default()
+        }
+    }
+}
+
+impl ScrollbarPolicy {
     /// Apply the policy to the scroll-flag received from the inner widget.
     pub fn apply(&self, scroll: bool) -> bool {
         match self {
@@ -578,10 +1486,26 @@ impl<WState: Default> Default for ScrolledState<WState> {
             view_area: Default::default(),
             h_scrollbar_area: None,
             v_scrollbar_area: None,
+            corner_area: None,
+            scrollbar_reason: Default::default(),
             v_overscroll: 0,
             h_overscroll: 0,
+            v_wheel_scroll_by: None,
+            h_wheel_scroll_by: None,
+            wheel_scroll_fraction: None,
+            need_scroll_cache: None,
+            v_animation: None,
+            h_animation: None,
+            v_flick: None,
+            h_flick: None,
+            flick_friction: 0.9,
+            scroll_capture_area: None,
+            mouse_pos: None,
             v_drag: false,
             h_drag: false,
+            drag_grab: 0,
+            pan_pos: None,
+            config: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -606,7 +1530,9 @@ impl<WState: ScrollingState> ScrolledState<WState> {
     pub fn set_vertical_offset(&mut self, offset: usize) -> bool {
         let voffset = min(
             offset,
-            self.widget.vertical_max_offset() + self.v_overscroll,
+            self.widget
+                .vertical_max_offset()
+                .saturating_add(self.v_overscroll),
         );
         self.widget.set_vertical_offset(voffset)
     }
@@ -619,11 +1545,27 @@ impl<WState: ScrollingState> ScrolledState<WState> {
     pub fn set_horizontal_offset(&mut self, offset: usize) -> bool {
         let hoffset = min(
             offset,
-            self.widget.horizontal_max_offset() + self.h_overscroll,
+            self.widget
+                .horizontal_max_offset()
+                .saturating_add(self.h_overscroll),
         );
         self.widget.set_horizontal_offset(hoffset)
     }
 
+    /// Change the offset like set_vertical_offset() and return
+    /// the offset the widget ended up with.
+    pub fn set_vertical_offset_clamped(&mut self, offset: usize) -> usize {
+        self.set_vertical_offset(offset);
+        self.widget.vertical_offset()
+    }
+
+    /// Change the offset like set_horizontal_offset() and return
+    /// the offset the widget ended up with.
+    pub fn set_horizontal_offset_clamped(&mut self, offset: usize) -> usize {
+        self.set_horizontal_offset(offset);
+        self.widget.horizontal_offset()
+    }
+
     /// Scroll up by n.
     pub fn scroll_up(&mut self, n: usize) -> bool {
         self.set_vertical_offset(self.vertical_offset().saturating_sub(n))
@@ -632,8 +1574,10 @@ impl<WState: ScrollingState> ScrolledState<WState> {
     /// Scroll down by n, but limited by the max_offset + overscroll
     pub fn scroll_down(&mut self, n: usize) -> bool {
         let v_offset = min(
-            self.widget.vertical_offset() + n,
-            self.widget.vertical_max_offset() + self.v_overscroll,
+            self.widget.vertical_offset().saturating_add(n),
+            self.widget
+                .vertical_max_offset()
+                .saturating_add(self.v_overscroll),
         );
         self.set_vertical_offset(v_offset)
     }
@@ -646,15 +1590,847 @@ impl<WState: ScrollingState> ScrolledState<WState> {
     /// Scroll right by n, but limited by the max_offset + overscroll
     pub fn scroll_right(&mut self, n: usize) -> bool {
         let hoffset = min(
-            self.widget.horizontal_offset() + n,
-            self.widget.horizontal_max_offset() + self.h_overscroll,
+            self.widget.horizontal_offset().saturating_add(n),
+            self.widget
+                .horizontal_max_offset()
+                .saturating_add(self.h_overscroll),
         );
         self.set_horizontal_offset(hoffset)
     }
 
+    /// Scroll by a signed delta, negative values scroll up.
+    pub fn scroll_vertical_by(&mut self, delta: isize) -> bool {
+        if delta < 0 {
+            self.scroll_up(delta.unsigned_abs())
+        } else {
+            self.scroll_down(delta.unsigned_abs())
+        }
+    }
+
+    /// Scroll by a signed delta, negative values scroll left.
+    pub fn scroll_horizontal_by(&mut self, delta: isize) -> bool {
+        if delta < 0 {
+            self.scroll_left(delta.unsigned_abs())
+        } else {
+            self.scroll_right(delta.unsigned_abs())
+        }
+    }
+
+    /// Why the (horizontal, vertical) scrollbars are shown
+    /// after the last render.
+    pub fn scrollbar_reason(&self) -> (ScrollbarShown, ScrollbarShown) {
+        self.scrollbar_reason
+    }
+
+    /// Would scroll_up(n) change the offset?
+    ///
+    /// Allows to decide who gets a wheel event before it is
+    /// applied, e.g. with nested scrolling.
+    pub fn can_scroll_up(&self, n: usize) -> bool {
+        n > 0 && self.widget.vertical_offset() > 0
+    }
+
+    /// Would scroll_down(n) change the offset?
+    pub fn can_scroll_down(&self, n: usize) -> bool {
+        n > 0
+            && self.widget.vertical_offset()
+                < self
+                    .widget
+                    .vertical_max_offset()
+                    .saturating_add(self.v_overscroll)
+    }
+
+    /// Would scroll_left(n) change the offset?
+    pub fn can_scroll_left(&self, n: usize) -> bool {
+        n > 0 && self.widget.horizontal_offset() > 0
+    }
+
+    /// Would scroll_right(n) change the offset?
+    pub fn can_scroll_right(&self, n: usize) -> bool {
+        n > 0
+            && self.widget.horizontal_offset()
+                < self
+                    .widget
+                    .horizontal_max_offset()
+                    .saturating_add(self.h_overscroll)
+    }
+
+    /// Range of the visible items of the inner widget.
+    ///
+    /// This is offset..offset + page, limited to the end of the
+    /// content at max_offset + page. On the last page the range
+    /// can be shorter than the page. With overscroll the range
+    /// may be empty.
+    pub fn vertical_visible(&self) -> Range<usize> {
+        visible_range(
+            self.widget.vertical_offset(),
+            self.widget.vertical_page(),
+            self.widget.vertical_max_offset(),
+        )
+    }
+
+    /// Range of the visible columns of the inner widget.
+    ///
+    /// This is offset..offset + page, limited to the end of the
+    /// content at max_offset + page. On the last page the range
+    /// can be shorter than the page. With overscroll the range
+    /// may be empty.
+    pub fn horizontal_visible(&self) -> Range<usize> {
+        visible_range(
+            self.widget.horizontal_offset(),
+            self.widget.horizontal_page(),
+            self.widget.horizontal_max_offset(),
+        )
+    }
+
+    /// Vertical offset as a fraction of the max_offset.
+    ///
+    /// Returns 0.0 if there is nothing to scroll.
+    pub fn vertical_scroll_percent(&self) -> f32 {
+        offset_percent(
+            self.widget.vertical_offset(),
+            self.widget.vertical_max_offset(),
+        )
+    }
+
+    /// Horizontal offset as a fraction of the max_offset.
+    ///
+    /// Returns 0.0 if there is nothing to scroll.
+    pub fn horizontal_scroll_percent(&self) -> f32 {
+        offset_percent(
+            self.widget.horizontal_offset(),
+            self.widget.horizontal_max_offset(),
+        )
+    }
+
+    /// Set the vertical offset as a fraction of the max_offset.
+    ///
+    /// The fraction is limited to 0.0..=1.0, 1.0 is exactly the max_offset.
+    pub fn set_vertical_scroll_percent(&mut self, percent: f32) -> bool {
+        let offset = percent_offset(percent, self.widget.vertical_max_offset());
+        self.set_vertical_offset(offset)
+    }
+
+    /// Set the horizontal offset as a fraction of the max_offset.
+    ///
+    /// The fraction is limited to 0.0..=1.0, 1.0 is exactly the max_offset.
+    pub fn set_horizontal_scroll_percent(&mut self, percent: f32) -> bool {
+        let offset = percent_offset(percent, self.widget.horizontal_max_offset());
+        self.set_horizontal_offset(offset)
+    }
+
+    /// Scroll step for the mouse wheel.
+    ///
+    /// Overrides the widgets vertical_scroll()/horizontal_scroll()
+    /// for wheel events.
+    pub fn set_wheel_scroll_by(&mut self, n: usize) {
+        self.v_wheel_scroll_by = Some(n);
+        self.h_wheel_scroll_by = Some(n);
+    }
+
+    /// Vertical scroll step for the mouse wheel.
+    ///
+    /// Overrides the widgets vertical_scroll() for wheel events.
+    pub fn set_vertical_wheel_scroll_by(&mut self, n: usize) {
+        self.v_wheel_scroll_by = Some(n);
+    }
+
+    /// Horizontal scroll step for the mouse wheel.
+    ///
+    /// Overrides the widgets horizontal_scroll() for wheel events.
+    /// This is used for SHIFT+wheel and ALT+wheel too.
+    pub fn set_horizontal_wheel_scroll_by(&mut self, n: usize) {
+        self.h_wheel_scroll_by = Some(n);
+    }
+
+    /// Scroll step for the mouse wheel as a fraction of the page,
+    /// e.g. 0.25 for a quarter page. The step is at least 1.
+    ///
+    /// A fixed step set with set_*_wheel_scroll_by() takes precedence.
+    pub fn set_wheel_scroll_fraction(&mut self, fraction: f32) {
+        self.wheel_scroll_fraction = Some(fraction);
+    }
+
+    /// Handle wheel events inside this area instead of the area
+    /// of the widget. E.g. a larger area that includes a header,
+    /// or the border of a surrounding panel.
+    ///
+    /// None resets to the area of the widget.
+    pub fn set_scroll_capture_area(&mut self, area: Option<Rect>) {
+        self.scroll_capture_area = area;
+    }
+
+    /// The area where wheel events are handled.
+    pub fn scroll_capture_area(&self) -> Rect {
+        self.scroll_capture_area.unwrap_or(self.area)
+    }
+
+    /// Vertical scroll step for the mouse wheel.
+    pub fn vertical_wheel_scroll(&self) -> usize {
+        if let Some(n) = self.v_wheel_scroll_by {
+            n
+        } else if let Some(fraction) = self.wheel_scroll_fraction {
+            fraction_step(self.widget.vertical_page(), fraction)
+        } else {
+            self.widget.vertical_scroll()
+        }
+    }
+
+    /// Horizontal scroll step for the mouse wheel.
+    pub fn horizontal_wheel_scroll(&self) -> usize {
+        if let Some(n) = self.h_wheel_scroll_by {
+            n
+        } else if let Some(fraction) = self.wheel_scroll_fraction {
+            fraction_step(self.widget.horizontal_page(), fraction)
+        } else {
+            self.widget.horizontal_scroll()
+        }
+    }
+
+    /// Position of the thumb of the vertical scrollbar.
+    ///
+    /// Returns the start and the length of the thumb relative
+    /// to the start of the v_scrollbar_area. This accounts for
+    /// the begin arrow and for min_thumb_len.
+    ///
+    /// Returns None if there is no scrollbar or the max_offset is 0.
+    pub fn vertical_thumb_bounds(&self) -> Option<(u16, u16)> {
+        let vscroll_area = self.v_scrollbar_area?;
+        let max_offset = self.widget.vertical_max_offset();
+        if max_offset == 0 {
+            return None;
+        }
+        let height = vscroll_area.height.saturating_sub(2 * self.arrow_len());
+        let page_len = scrollbar_page_len(
+            max_offset,
+            self.widget.vertical_page(),
+            height,
+            self.config.min_thumb_len,
+        );
+        let (start, len) =
+            thumb_bounds(max_offset, self.widget.vertical_offset(), page_len, height);
+        Some((start + self.arrow_len(), len))
+    }
+
+    /// Position of the thumb of the horizontal scrollbar.
+    ///
+    /// Returns the start and the length of the thumb relative
+    /// to the start of the h_scrollbar_area. This accounts for
+    /// the begin arrow and for min_thumb_len.
+    ///
+    /// Returns None if there is no scrollbar or the max_offset is 0.
+    pub fn horizontal_thumb_bounds(&self) -> Option<(u16, u16)> {
+        let hscroll_area = self.h_scrollbar_area?;
+        let max_offset = self.widget.horizontal_max_offset();
+        if max_offset == 0 {
+            return None;
+        }
+        let width = hscroll_area.width.saturating_sub(2 * self.arrow_len());
+        let page_len = scrollbar_page_len(
+            max_offset,
+            self.widget.horizontal_page(),
+            width,
+            self.config.min_thumb_len,
+        );
+        let (start, len) =
+            thumb_bounds(max_offset, self.widget.horizontal_offset(), page_len, width);
+        Some((start + self.arrow_len(), len))
+    }
+
+    /// Start an animation towards the target offset.
+    ///
+    /// Each call to [ScrolledState::tick] moves the offset by at
+    /// most step. A step of 0 jumps to the target with the next tick.
+    pub fn animate_vertical_to(&mut self, target: usize, step: usize) {
+        self.v_animation = Some((target, step));
+        self.v_flick = None;
+    }
+
+    /// Start an animation towards the target offset.
+    ///
+    /// Each call to [ScrolledState::tick] moves the offset by at
+    /// most step. A step of 0 jumps to the target with the next tick.
+    pub fn animate_horizontal_to(&mut self, target: usize, step: usize) {
+        self.h_animation = Some((target, step));
+        self.h_flick = None;
+    }
+
+    /// Start a flick with the given velocity in items per tick.
+    /// Negative values scroll up.
+    ///
+    /// Each call to [ScrolledState::tick] applies the velocity
+    /// and slows it down by the flick_friction. This replaces
+    /// a running animate_vertical_to() and vice versa.
+    pub fn apply_vertical_flick(&mut self, velocity: f32) {
+        self.v_flick = Some((velocity, 0.0));
+        self.v_animation = None;
+    }
+
+    /// Start a flick with the given velocity in items per tick.
+    /// Negative values scroll left.
+    ///
+    /// Each call to [ScrolledState::tick] applies the velocity
+    /// and slows it down by the flick_friction. This replaces
+    /// a running animate_horizontal_to() and vice versa.
+    pub fn apply_horizontal_flick(&mut self, velocity: f32) {
+        self.h_flick = Some((velocity, 0.0));
+        self.h_animation = None;
+    }
+
+    /// Friction for flicks. The velocity is multiplied with
+    /// this value for every tick. Default is 0.9.
+    pub fn set_flick_friction(&mut self, friction: f32) {
+        self.flick_friction = friction.clamp(0.0, 1.0);
+    }
+
+    /// Is one of the scrollbars being dragged with the mouse?
+    pub fn is_dragging(&self) -> bool {
+        self.v_drag || self.h_drag
+    }
+
+    /// Is there an animation running?
+    pub fn is_animating(&self) -> bool {
+        self.v_animation.is_some()
+            || self.h_animation.is_some()
+            || self.v_flick.is_some()
+            || self.h_flick.is_some()
+    }
+
+    /// Advance the running animations by one step.
+    ///
+    /// Returns true if the offset changed. The application
+    /// should render in that case, and schedule another tick as
+    /// long as [ScrolledState::is_animating]. A slow flick can
+    /// need a few ticks without change to move by one item.
+    ///
+    /// The animation ends when the target is reached or the
+    /// offset can't change anymore. A flick ends when the
+    /// velocity drops below 0.1 or at the start/end.
+    pub fn tick(&mut self) -> bool {
+        let mut changed = false;
+
+        if let Some((target, step)) = self.v_animation {
+            let offset = self.widget.vertical_offset();
+            let next = animation_step(offset, target, step);
+            let moved = self.set_vertical_offset(next);
+            if !moved || self.widget.vertical_offset() == target {
+                self.v_animation = None;
+            }
+            changed |= moved;
+        }
+        if let Some((target, step)) = self.h_animation {
+            let offset = self.widget.horizontal_offset();
+            let next = animation_step(offset, target, step);
+            let moved = self.set_horizontal_offset(next);
+            if !moved || self.widget.horizontal_offset() == target {
+                self.h_animation = None;
+            }
+            changed |= moved;
+        }
+
+        if let Some(flick) = self.v_flick {
+            let (n, flick) = flick_step(flick, self.flick_friction);
+            let moved = if n < 0 {
+                self.scroll_up(n.unsigned_abs())
+            } else {
+                self.scroll_down(n as usize)
+            };
+            self.v_flick = if n != 0 && !moved { None } else { flick };
+            changed |= moved;
+        }
+        if let Some(flick) = self.h_flick {
+            let (n, flick) = flick_step(flick, self.flick_friction);
+            let moved = if n < 0 {
+                self.scroll_left(n.unsigned_abs())
+            } else {
+                self.scroll_right(n as usize)
+            };
+            self.h_flick = if n != 0 && !moved { None } else { flick };
+            changed |= moved;
+        }
+
+        changed
+    }
+
+    /// Limit the offsets of the inner widget to max_offset + overscroll.
+    ///
+    /// Use this between frames, after the inner state has been changed
+    /// directly, e.g. via [ScrolledState::widget_mut]. Afterwards the
+    /// offsets of this state are consistent again without waiting for
+    /// the next render.
+    ///
+    /// Ordering: the max_offsets are whatever the inner widget reports
+    /// now. For most widgets they are calculated during render, so
+    /// this is only as accurate as the last render. If the area or the
+    /// content changed since, the next render may limit the offsets
+    /// again.
+    ///
+    /// Returns true if any offset changed.
+    pub fn sync_from_inner(&mut self) -> bool {
+        let v_changed = self.set_vertical_offset(self.widget.vertical_offset());
+        let h_changed = self.set_horizontal_offset(self.widget.horizontal_offset());
+        v_changed || h_changed
+    }
+
+    /// Length of the begin/end arrows of the scrollbars.
+    fn arrow_len(&self) -> u16 {
+        if self.config.no_arrows {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Thumb bounds of the vertical scrollbar, if the mouse is over the thumb.
+    fn vertical_thumb_hover(&self) -> Option<(u16, u16)> {
+        let mouse_pos = self.mouse_pos?;
+        let vscroll_area = self.v_scrollbar_area?;
+        let (thumb_start, thumb_len) = self.vertical_thumb_bounds()?;
+        let row = mouse_pos.y.checked_sub(vscroll_area.y)?;
+        if vscroll_area.contains(mouse_pos) && row >= thumb_start && row < thumb_start + thumb_len {
+            Some((thumb_start, thumb_len))
+        } else {
+            None
+        }
+    }
+
+    /// Thumb bounds of the horizontal scrollbar, if the mouse is over the thumb.
+    fn horizontal_thumb_hover(&self) -> Option<(u16, u16)> {
+        let mouse_pos = self.mouse_pos?;
+        let hscroll_area = self.h_scrollbar_area?;
+        let (thumb_start, thumb_len) = self.horizontal_thumb_bounds()?;
+        let col = mouse_pos.x.checked_sub(hscroll_area.x)?;
+        if hscroll_area.contains(mouse_pos) && col >= thumb_start && col < thumb_start + thumb_len {
+            Some((thumb_start, thumb_len))
+        } else {
+            None
+        }
+    }
+
+    /// Invalidates the cached result of [ScrollingWidget::need_scroll].
+    ///
+    /// Call this when the content of the widget changes and
+    /// [Scrolled::cache_need_scroll] is set.
+    pub fn invalidate(&mut self) {
+        self.need_scroll_cache = None;
+    }
+
     pub fn widget_mut(&mut self) -> &mut WState {
         &mut self.widget
     }
+
+    /// Scroll handling for the scrollbars and the mouse wheel.
+    ///
+    /// Any input that hits the scrollbars and wheel input
+    /// inside the area is consumed, even if the offset doesn't change.
+    /// Wheel input that can't scroll any further returns
+    /// AtStart/AtEnd instead of Unchanged.
+    /// This way the topmost of some stacked scrolled widgets
+    /// (e.g. a popup over a list) stops further event processing
+    /// with flow!() and the widget below doesn't scroll too.
+    pub fn handle_input<R>(&mut self, input: ScrollInput) -> ScrollOutcome<R> {
+        let input = if self.config.invert_wheel {
+            match input {
+                ScrollInput::ScrollUp(pos) => ScrollInput::ScrollDown(pos),
+                ScrollInput::ScrollDown(pos) => ScrollInput::ScrollUp(pos),
+                ScrollInput::ScrollPageUp(pos) => ScrollInput::ScrollPageDown(pos),
+                ScrollInput::ScrollPageDown(pos) => ScrollInput::ScrollPageUp(pos),
+                ScrollInput::ScrollLeft(pos) => ScrollInput::ScrollRight(pos),
+                ScrollInput::ScrollRight(pos) => ScrollInput::ScrollLeft(pos),
+                ScrollInput::ScrollBy { pos, h, v } => ScrollInput::ScrollBy {
+                    pos,
+                    h: h.saturating_neg(),
+                    v: v.saturating_neg(),
+                },
+                input => input,
+            }
+        } else {
+            input
+        };
+
+        match input {
+            // Click on one of the scrollbar sets the offset to
+            // the scaled up position.
+            ScrollInput::Press(pos) => {
+                if let Some(vscroll_area) = self.v_scrollbar_area {
+                    if vscroll_area.contains(pos) {
+                        // click on the arrows scrolls by one step.
+                        if self.arrow_len() == 0 {
+                            // no arrows, the track fills the scrollbar.
+                        } else if pos.y == vscroll_area.y {
+                            if self.scroll_up(self.widget.vertical_scroll()) {
+                                return ScrollOutcome::Changed;
+                            } else {
+                                return ScrollOutcome::Unchanged;
+                            }
+                        } else if pos.y + 1 == vscroll_area.bottom() {
+                            if self.scroll_down(self.widget.vertical_scroll()) {
+                                return ScrollOutcome::Changed;
+                            } else {
+                                return ScrollOutcome::Unchanged;
+                            }
+                        }
+
+                        // correct for the top `^` and bottom `v` arrows.
+                        let row = pos
+                            .y
+                            .saturating_sub(vscroll_area.y)
+                            .saturating_sub(self.arrow_len());
+                        let height = vscroll_area.height.saturating_sub(2 * self.arrow_len());
+
+                        let max_offset = self.widget.vertical_max_offset();
+                        let page_len = scrollbar_page_len(
+                            max_offset,
+                            self.widget.vertical_page(),
+                            height,
+                            self.config.min_thumb_len,
+                        );
+
+                        let (thumb_start, thumb_len) = thumb_bounds(
+                            max_offset,
+                            self.widget.vertical_offset(),
+                            page_len,
+                            height,
+                        );
+                        if row >= thumb_start && row < thumb_start + thumb_len {
+                            // grab the thumb where it was clicked.
+                            self.drag_grab = row - thumb_start;
+                            self.v_drag = true;
+                            return ScrollOutcome::Unchanged;
+                        }
+
+                        if self.config.scrollbar_interaction == ScrollbarInteraction::Page {
+                            if row < thumb_start {
+                                if self.scroll_up(self.widget.vertical_page()) {
+                                    return ScrollOutcome::Changed;
+                                }
+                            } else if self.scroll_down(self.widget.vertical_page()) {
+                                return ScrollOutcome::Changed;
+                            }
+                            return ScrollOutcome::Unchanged;
+                        }
+
+                        let pos = track_pos_offset(row, max_offset, page_len, height);
+
+                        // the thumb starts at the clicked position.
+                        self.drag_grab = 0;
+                        self.v_drag = true;
+                        if self.widget.set_vertical_offset(pos) {
+                            return ScrollOutcome::Changed;
+                        } else {
+                            return ScrollOutcome::Unchanged;
+                        }
+                    }
+                }
+                if let Some(hscroll_area) = self.h_scrollbar_area {
+                    if hscroll_area.contains(pos) {
+                        // click on the arrows scrolls by one step.
+                        if self.arrow_len() == 0 {
+                            // no arrows, the track fills the scrollbar.
+                        } else if pos.x == hscroll_area.x {
+                            if self.scroll_left(self.widget.horizontal_scroll()) {
+                                return ScrollOutcome::Changed;
+                            } else {
+                                return ScrollOutcome::Unchanged;
+                            }
+                        } else if pos.x + 1 == hscroll_area.right() {
+                            if self.scroll_right(self.widget.horizontal_scroll()) {
+                                return ScrollOutcome::Changed;
+                            } else {
+                                return ScrollOutcome::Unchanged;
+                            }
+                        }
+
+                        // correct for the left `<` and right `>` arrows.
+                        let col = pos
+                            .x
+                            .saturating_sub(hscroll_area.x)
+                            .saturating_sub(self.arrow_len());
+                        let width = hscroll_area.width.saturating_sub(2 * self.arrow_len());
+
+                        let max_offset = self.widget.horizontal_max_offset();
+                        let page_len = scrollbar_page_len(
+                            max_offset,
+                            self.widget.horizontal_page(),
+                            width,
+                            self.config.min_thumb_len,
+                        );
+
+                        let (thumb_start, thumb_len) = thumb_bounds(
+                            max_offset,
+                            self.widget.horizontal_offset(),
+                            page_len,
+                            width,
+                        );
+                        if col >= thumb_start && col < thumb_start + thumb_len {
+                            // grab the thumb where it was clicked.
+                            self.drag_grab = col - thumb_start;
+                            self.h_drag = true;
+                            return ScrollOutcome::Unchanged;
+                        }
+
+                        if self.config.scrollbar_interaction == ScrollbarInteraction::Page {
+                            if col < thumb_start {
+                                if self.scroll_left(self.widget.horizontal_page()) {
+                                    return ScrollOutcome::Changed;
+                                }
+                            } else if self.scroll_right(self.widget.horizontal_page()) {
+                                return ScrollOutcome::Changed;
+                            }
+                            return ScrollOutcome::Unchanged;
+                        }
+
+                        let pos = track_pos_offset(col, max_offset, page_len, width);
+
+                        // the thumb starts at the clicked position.
+                        self.drag_grab = 0;
+                        self.h_drag = true;
+                        if self.widget.set_horizontal_offset(pos) {
+                            return ScrollOutcome::Changed;
+                        } else {
+                            return ScrollOutcome::Unchanged;
+                        }
+                    }
+                }
+            }
+            // the same as before with drag events.
+            ScrollInput::Drag(pos) => {
+                if self.v_drag {
+                    if let Some(vscroll_area) = self.v_scrollbar_area {
+                        // correct for the top `^` and bottom `v` arrows,
+                        // and keep the thumb where it was grabbed.
+                        let row = pos
+                            .y
+                            .saturating_sub(vscroll_area.y)
+                            .saturating_sub(self.arrow_len())
+                            .saturating_sub(self.drag_grab);
+                        let height = vscroll_area.height.saturating_sub(2 * self.arrow_len());
+
+                        let max_offset = self.widget.vertical_max_offset();
+                        let page_len = scrollbar_page_len(
+                            max_offset,
+                            self.widget.vertical_page(),
+                            height,
+                            self.config.min_thumb_len,
+                        );
+                        let pos = track_pos_offset(row, max_offset, page_len, height);
+
+                        if self.set_vertical_offset(pos) {
+                            return ScrollOutcome::Changed;
+                        } else {
+                            return ScrollOutcome::Unchanged;
+                        }
+                    }
+                }
+                if self.h_drag {
+                    if let Some(hscroll_area) = self.h_scrollbar_area {
+                        // correct for the left `<` and right `>` arrows,
+                        // and keep the thumb where it was grabbed.
+                        let col = pos
+                            .x
+                            .saturating_sub(hscroll_area.x)
+                            .saturating_sub(self.arrow_len())
+                            .saturating_sub(self.drag_grab);
+                        let width = hscroll_area.width.saturating_sub(2 * self.arrow_len());
+
+                        let max_offset = self.widget.horizontal_max_offset();
+                        let page_len = scrollbar_page_len(
+                            max_offset,
+                            self.widget.horizontal_page(),
+                            width,
+                            self.config.min_thumb_len,
+                        );
+                        let pos = track_pos_offset(col, max_offset, page_len, width);
+                        if self.set_horizontal_offset(pos) {
+                            return ScrollOutcome::Changed;
+                        } else {
+                            return ScrollOutcome::Unchanged;
+                        }
+                    }
+                }
+            }
+
+            ScrollInput::ShiftDrag(pos) => {
+                if self.config.cross_axis_drag && self.v_drag {
+                    if let Some(vscroll_area) = self.v_scrollbar_area {
+                        // correct for the top `^` and bottom `v` arrows.
+                        let row = pos
+                            .y
+                            .saturating_sub(vscroll_area.y)
+                            .saturating_sub(self.arrow_len());
+                        let height = vscroll_area.height.saturating_sub(2 * self.arrow_len());
+
+                        let max_offset = self.widget.horizontal_max_offset();
+                        let page_len = scrollbar_page_len(
+                            max_offset,
+                            self.widget.horizontal_page(),
+                            height,
+                            self.config.min_thumb_len,
+                        );
+                        let pos = track_pos_offset(row, max_offset, page_len, height);
+                        if self.set_horizontal_offset(pos) {
+                            return ScrollOutcome::Changed;
+                        } else {
+                            return ScrollOutcome::Unchanged;
+                        }
+                    }
+                }
+                return self.handle_input(ScrollInput::Drag(pos));
+            }
+
+            ScrollInput::Moved(pos) => {
+                // reset drag
+                self.v_drag = false;
+                self.h_drag = false;
+                self.drag_grab = 0;
+                self.pan_pos = None;
+
+                let old_hover = (self.vertical_thumb_hover(), self.horizontal_thumb_hover());
+                self.mouse_pos = if self.area.contains(pos) {
+                    Some(pos)
+                } else {
+                    None
+                };
+                let hover = (self.vertical_thumb_hover(), self.horizontal_thumb_hover());
+                if old_hover != hover {
+                    return ScrollOutcome::Changed;
+                }
+            }
+
+            ScrollInput::PanStart(pos) => {
+                if self.config.pan && self.view_area.contains(pos) {
+                    self.pan_pos = Some(pos);
+                    return ScrollOutcome::Unchanged;
+                }
+            }
+            ScrollInput::Pan(pos) => {
+                if let Some(last_pos) = self.pan_pos {
+                    self.pan_pos = Some(pos);
+                    // the content follows the mouse.
+                    let v_changed = if pos.y < last_pos.y {
+                        self.scroll_down((last_pos.y - pos.y) as usize)
+                    } else {
+                        self.scroll_up((pos.y - last_pos.y) as usize)
+                    };
+                    let h_changed = if pos.x < last_pos.x {
+                        self.scroll_right((last_pos.x - pos.x) as usize)
+                    } else {
+                        self.scroll_left((pos.x - last_pos.x) as usize)
+                    };
+                    if v_changed || h_changed {
+                        return ScrollOutcome::Changed;
+                    } else {
+                        return ScrollOutcome::Unchanged;
+                    }
+                }
+            }
+
+            ScrollInput::ScrollDown(pos) => {
+                if self.scroll_capture_area().contains(pos) {
+                    let n = self.vertical_wheel_scroll();
+                    if self.scroll_down(n) {
+                        return ScrollOutcome::Changed;
+                    } else if self.config.pass_through_at_bounds {
+                        return ScrollOutcome::NotUsed;
+                    } else if n > 0 {
+                        return ScrollOutcome::AtEnd;
+                    } else {
+                        return ScrollOutcome::Unchanged;
+                    }
+                }
+            }
+            ScrollInput::ScrollUp(pos) => {
+                if self.scroll_capture_area().contains(pos) {
+                    let n = self.vertical_wheel_scroll();
+                    if self.scroll_up(n) {
+                        return ScrollOutcome::Changed;
+                    } else if self.config.pass_through_at_bounds {
+                        return ScrollOutcome::NotUsed;
+                    } else if n > 0 {
+                        return ScrollOutcome::AtStart;
+                    } else {
+                        return ScrollOutcome::Unchanged;
+                    }
+                }
+            }
+            ScrollInput::ScrollPageDown(pos) => {
+                if self.config.ctrl_wheel_page && self.scroll_capture_area().contains(pos) {
+                    if self.scroll_down(self.widget.vertical_page()) {
+                        return ScrollOutcome::Changed;
+                    } else if self.config.pass_through_at_bounds {
+                        return ScrollOutcome::NotUsed;
+                    } else {
+                        return ScrollOutcome::AtEnd;
+                    }
+                }
+            }
+            ScrollInput::ScrollPageUp(pos) => {
+                if self.config.ctrl_wheel_page && self.scroll_capture_area().contains(pos) {
+                    if self.scroll_up(self.widget.vertical_page()) {
+                        return ScrollOutcome::Changed;
+                    } else if self.config.pass_through_at_bounds {
+                        return ScrollOutcome::NotUsed;
+                    } else {
+                        return ScrollOutcome::AtStart;
+                    }
+                }
+            }
+            ScrollInput::ScrollRight(pos) => {
+                if self.scroll_capture_area().contains(pos) {
+                    let n = self.horizontal_wheel_scroll();
+                    if self.scroll_right(n) {
+                        return ScrollOutcome::Changed;
+                    } else if self.config.pass_through_at_bounds {
+                        return ScrollOutcome::NotUsed;
+                    } else if n > 0 {
+                        return ScrollOutcome::AtEnd;
+                    } else {
+                        return ScrollOutcome::Unchanged;
+                    }
+                }
+            }
+            ScrollInput::ScrollLeft(pos) => {
+                if self.scroll_capture_area().contains(pos) {
+                    let n = self.horizontal_wheel_scroll();
+                    if self.scroll_left(n) {
+                        return ScrollOutcome::Changed;
+                    } else if self.config.pass_through_at_bounds {
+                        return ScrollOutcome::NotUsed;
+                    } else if n > 0 {
+                        return ScrollOutcome::AtStart;
+                    } else {
+                        return ScrollOutcome::Unchanged;
+                    }
+                }
+            }
+            ScrollInput::ScrollBy { pos, h, v } => {
+                if self.scroll_capture_area().contains(pos) {
+                    let h_step =
+                        (h.unsigned_abs() as usize).saturating_mul(self.horizontal_wheel_scroll());
+                    let v_step =
+                        (v.unsigned_abs() as usize).saturating_mul(self.vertical_wheel_scroll());
+                    let h_changed = if h < 0 {
+                        self.scroll_left(h_step)
+                    } else {
+                        self.scroll_right(h_step)
+                    };
+                    let v_changed = if v < 0 {
+                        self.scroll_up(v_step)
+                    } else {
+                        self.scroll_down(v_step)
+                    };
+                    if h_changed || v_changed {
+                        return ScrollOutcome::Changed;
+                    } else if self.config.pass_through_at_bounds {
+                        return ScrollOutcome::NotUsed;
+                    } else {
+                        return ScrollOutcome::Unchanged;
+                    }
+                }
+            }
+        }
+        ScrollOutcome::NotUsed
+    }
 }
 
 /// A way to call event-handlers for the inner widget.
@@ -673,13 +2449,13 @@ impl<WState: ScrollingState> ScrolledState<WState> {
 pub struct Inner<Qualifier>(pub Qualifier);
 
 /// Forward event-handling to the inner widget.
-impl<WState, Q, R> HandleEvent<crossterm::event::Event, Inner<Q>, ScrollOutcome<R>>
-    for ScrolledState<WState>
+impl<E, WState, Q, R> HandleEvent<E, Inner<Q>, ScrollOutcome<R>> for ScrolledState<WState>
 where
-    WState: ScrollingState + HandleEvent<crossterm::event::Event, Q, R>,
+    E: ScrollEvent,
+    WState: ScrollingState + HandleEvent<E, Q, R>,
     R: ConsumedEvent,
 {
-    fn handle(&mut self, event: &crossterm::event::Event, qualifier: Inner<Q>) -> ScrollOutcome<R> {
+    fn handle(&mut self, event: &E, qualifier: Inner<Q>) -> ScrollOutcome<R> {
         forward_filter(self, event, qualifier.0) // ...
             .or_else(|| mouse_handling(self, event, MouseOnly))
     }
@@ -696,171 +2472,301 @@ where
 {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: FocusKeys) -> ScrollOutcome<R> {
         forward_filter(self, event, FocusKeys) // ...
+            .or_else(|| key_handling(self, event, FocusKeys))
             .or_else(|| mouse_handling(self, event, MouseOnly))
     }
 }
 
 /// Handle events for the Scrolled widget and the scrollbars.
-impl<R, WState> HandleEvent<crossterm::event::Event, MouseOnly, ScrollOutcome<R>>
-    for ScrolledState<WState>
+impl<E, R, WState> HandleEvent<E, MouseOnly, ScrollOutcome<R>> for ScrolledState<WState>
 where
-    WState: ScrollingState + HandleEvent<crossterm::event::Event, MouseOnly, R>,
+    E: ScrollEvent,
+    WState: ScrollingState + HandleEvent<E, MouseOnly, R>,
     R: ConsumedEvent,
 {
-    fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> ScrollOutcome<R> {
+    fn handle(&mut self, event: &E, _keymap: MouseOnly) -> ScrollOutcome<R> {
         forward_filter(self, event, MouseOnly) // ...
             .or_else(|| mouse_handling(self, event, MouseOnly))
     }
 }
 
 // only mouse handling for the scrolled widget itself.
-fn mouse_handling<W, R>(
+fn mouse_handling<E, W, R>(
     widget: &mut ScrolledState<W>,
-    event: &crossterm::event::Event,
+    event: &E,
     _qualifier: MouseOnly,
 ) -> ScrollOutcome<R>
 where
+    E: ScrollEvent,
     W: ScrollingState,
     R: ConsumedEvent,
 {
-    match event {
-        // Click on one of the scrollbar sets the offset to
-        // the scaled up position.
-        ct_event!(mouse down Left for column,row) => {
-            if let Some(vscroll_area) = widget.v_scrollbar_area {
-                if vscroll_area.contains(Position::new(*column, *row)) {
-                    // correct for the top `^` and bottom `v` arrows.
-                    let row = row.saturating_sub(vscroll_area.y).saturating_sub(1) as usize;
-                    let height = vscroll_area.height.saturating_sub(2) as usize;
-
-                    let pos = (widget.widget.vertical_max_offset() * row) / height;
-
-                    widget.v_drag = true;
-                    if widget.widget.set_vertical_offset(pos) {
-                        return ScrollOutcome::Changed;
-                    } else {
-                        return ScrollOutcome::NotUsed;
-                    }
-                }
-            }
-            if let Some(hscroll_area) = widget.h_scrollbar_area {
-                if hscroll_area.contains(Position::new(*column, *row)) {
-                    // correct for the left `<` and right `>` arrows.
-                    let col = column.saturating_sub(hscroll_area.x).saturating_sub(1) as usize;
-                    let width = hscroll_area.width.saturating_sub(2) as usize;
-
-                    let pos = (widget.widget.horizontal_max_offset() * col) / width;
+    match event.scroll_input(!widget.config.no_alt_scroll) {
+        Some(input) => widget.handle_input(input),
+        None => ScrollOutcome::NotUsed,
+    }
+}
 
-                    widget.h_drag = true;
-                    if widget.widget.set_horizontal_offset(pos) {
-                        return ScrollOutcome::Changed;
-                    } else {
-                        return ScrollOutcome::NotUsed;
-                    }
-                }
+// keys not used by the inner widget scroll the view.
+fn key_handling<W, R>(
+    widget: &mut ScrolledState<W>,
+    event: &crossterm::event::Event,
+    _qualifier: FocusKeys,
+) -> ScrollOutcome<R>
+where
+    W: ScrollingState,
+    R: ConsumedEvent,
+{
+    let r = match event {
+        ct_event!(keycode press Up) => {
+            if widget.scroll_up(1) {
+                return ScrollOutcome::Changed;
+            } else if widget.config.pass_through_at_bounds {
+                return ScrollOutcome::NotUsed;
+            } else {
+                return ScrollOutcome::AtStart;
             }
         }
-        // the same as before with drag events.
-        ct_event!(mouse drag Left for column, row) => {
-            if widget.v_drag {
-                if let Some(vscroll_area) = widget.v_scrollbar_area {
-                    // correct for the top `^` and bottom `v` arrows.
-                    let row = row.saturating_sub(vscroll_area.y).saturating_sub(1) as usize;
-                    let height = vscroll_area.height.saturating_sub(2) as usize;
-
-                    let pos = (widget.widget.vertical_max_offset() * row) / height;
-
-                    if widget.set_vertical_offset(pos) {
-                        return ScrollOutcome::Changed;
-                    } else {
-                        return ScrollOutcome::NotUsed;
-                    }
-                }
-            }
-            if widget.h_drag {
-                if let Some(hscroll_area) = widget.h_scrollbar_area {
-                    // correct for the left `<` and right `>` arrows.
-                    let col = column.saturating_sub(hscroll_area.x).saturating_sub(1) as usize;
-                    let width = hscroll_area.width.saturating_sub(2) as usize;
-
-                    let pos = (col * widget.widget.horizontal_max_offset()) / width;
-                    if widget.set_horizontal_offset(pos) {
-                        return ScrollOutcome::Changed;
-                    } else {
-                        return ScrollOutcome::NotUsed;
-                    }
-                }
+        ct_event!(keycode press Down) => {
+            if widget.scroll_down(1) {
+                return ScrollOutcome::Changed;
+            } else if widget.config.pass_through_at_bounds {
+                return ScrollOutcome::NotUsed;
+            } else {
+                return ScrollOutcome::AtEnd;
             }
         }
-
-        ct_event!(mouse moved) => {
-            // reset drag
-            widget.v_drag = false;
-            widget.h_drag = false;
-        }
-
-        ct_event!(scroll down for column, row) => {
-            if widget.area.contains(Position::new(*column, *row)) {
-                if widget.scroll_down(widget.widget.vertical_scroll()) {
-                    return ScrollOutcome::Changed;
-                } else {
-                    return ScrollOutcome::NotUsed;
-                }
+        ct_event!(keycode press PageUp) => {
+            if widget.scroll_up(widget.widget.vertical_page()) {
+                return ScrollOutcome::Changed;
+            } else if widget.config.pass_through_at_bounds {
+                return ScrollOutcome::NotUsed;
+            } else {
+                return ScrollOutcome::AtStart;
             }
         }
-        ct_event!(scroll up for column, row) => {
-            if widget.area.contains(Position::new(*column, *row)) {
-                if widget.widget.scroll_up(widget.widget.vertical_scroll()) {
-                    return ScrollOutcome::Changed;
-                } else {
-                    return ScrollOutcome::NotUsed;
-                }
+        ct_event!(keycode press PageDown) => {
+            if widget.scroll_down(widget.widget.vertical_page()) {
+                return ScrollOutcome::Changed;
+            } else if widget.config.pass_through_at_bounds {
+                return ScrollOutcome::NotUsed;
+            } else {
+                return ScrollOutcome::AtEnd;
             }
         }
-        // right scroll with ALT down. shift doesn't work?
-        ct_event!(scroll ALT down for column, row) => {
-            if widget.area.contains(Position::new(*column, *row)) {
-                if widget.scroll_right(widget.widget.horizontal_scroll()) {
-                    return ScrollOutcome::Changed;
-                } else {
-                    return ScrollOutcome::NotUsed;
-                }
-            }
+        ct_event!(keycode press Home) | ct_event!(keycode press CONTROL-Home) => {
+            widget.set_vertical_offset(0)
         }
-        // left scroll with ALT up. shift doesn't work?
-        ct_event!(scroll ALT up for column, row) => {
-            if widget.area.contains(Position::new(*column, *row)) {
-                if widget.widget.scroll_left(widget.widget.horizontal_scroll()) {
-                    return ScrollOutcome::Changed;
-                } else {
-                    return ScrollOutcome::NotUsed;
-                }
-            }
+        ct_event!(keycode press End) | ct_event!(keycode press CONTROL-End) => {
+            widget.set_vertical_offset(widget.widget.vertical_max_offset())
         }
-        _ => {}
+        _ => return ScrollOutcome::NotUsed,
+    };
+    if r {
+        ScrollOutcome::Changed
+    } else {
+        ScrollOutcome::Unchanged
+    }
+}
+
+/// Position and length of a scrollbar with a limited track_length.
+fn limit_track_length(
+    start: u16,
+    len: u16,
+    track_length: Option<u16>,
+    arrow_len: u16,
+    align: Alignment,
+) -> (u16, u16) {
+    let Some(track_length) = track_length else {
+        return (start, len);
+    };
+    let limited_len = min(len, track_length.saturating_add(2 * arrow_len));
+    let free = len - limited_len;
+    let start = match align {
+        Alignment::Left => start,
+        Alignment::Center => start + free / 2,
+        Alignment::Right => start + free,
+    };
+    (start, limited_len)
+}
+
+/// Page length used for the scrollbar.
+///
+/// Enlarges the page_len if necessary to get a thumb of at
+/// least min_thumb_len.
+fn scrollbar_page_len(
+    max_offset: usize,
+    page_len: usize,
+    track_len: u16,
+    min_thumb_len: u16,
+) -> usize {
+    let page_len = if page_len == 0 {
+        track_len as usize
+    } else {
+        page_len
+    };
+    if min_thumb_len == 0 || min_thumb_len >= track_len {
+        return page_len;
+    }
+
+    // thumb_len = page_len * track_len / (max_offset - 1 + page_len)
+    // widen to avoid an overflow with a large max_offset.
+    let min_thumb_len = min_thumb_len as u128;
+    let free_len = track_len as u128 - min_thumb_len;
+    let min_page_len = (min_thumb_len * max_offset.saturating_sub(1) as u128).div_ceil(free_len);
+
+    max(page_len, min(min_page_len, usize::MAX as u128) as usize)
+}
+
+/// Position of an item on the scrollbar track.
+///
+/// Uses the same scaling as the thumb. None if the item is
+/// beyond the end of the content.
+fn marker_pos(index: usize, max_offset: usize, page_len: usize, track_len: u16) -> Option<u16> {
+    let max_viewport_position = max_offset.saturating_sub(1).saturating_add(page_len);
+    if track_len == 0 || max_viewport_position == 0 || index > max_viewport_position {
+        return None;
+    }
+    let pos = (index as u128 * track_len as u128) / max_viewport_position as u128;
+    Some(min(pos, track_len as u128 - 1) as u16)
+}
+
+/// Offset for a position on the scrollbar track.
+///
+/// This is the offset where the thumb starts at this position,
+/// limited to max_offset.
+fn track_pos_offset(track_pos: u16, max_offset: usize, page_len: usize, track_len: u16) -> usize {
+    if track_len == 0 {
+        return 0;
+    }
+    let max_viewport_position = max_offset.saturating_sub(1).saturating_add(page_len);
+    // widen to avoid an overflow with a large max_offset.
+    let offset = (track_pos as u128 * max_viewport_position as u128) / track_len as u128;
+    min(offset, max_offset as u128) as usize
+}
+
+/// Scroll step as a fraction of the page, at least 1.
+fn fraction_step(page_len: usize, fraction: f32) -> usize {
+    let step = (page_len as f64 * fraction as f64).round();
+    if step.is_nan() || step < 1.0 {
+        1
+    } else {
+        step as usize
+    }
+}
+
+/// Distance to move for this tick and the next state of the flick.
+fn flick_step(flick: (f32, f32), friction: f32) -> (isize, Option<(f32, f32)>) {
+    let (velocity, rest) = flick;
+    let distance = velocity + rest;
+    let n = distance.trunc();
+    let rest = distance - n;
+
+    let velocity = velocity * friction;
+    if velocity.abs() < 0.1 || velocity.is_nan() {
+        (n as isize, None)
+    } else {
+        (n as isize, Some((velocity, rest)))
+    }
+}
+
+/// Next offset on the way to target.
+fn animation_step(offset: usize, target: usize, step: usize) -> usize {
+    if step == 0 {
+        target
+    } else if offset < target {
+        min(offset.saturating_add(step), target)
+    } else {
+        max(offset.saturating_sub(step), target)
+    }
+}
+
+/// Visible range for the offset, limited to the content.
+fn visible_range(offset: usize, page_len: usize, max_offset: usize) -> Range<usize> {
+    let len = max_offset.saturating_add(page_len);
+    let start = min(offset, len);
+    let end = min(offset.saturating_add(page_len), len);
+    start..end
+}
+
+/// Offset as fraction of max_offset.
+fn offset_percent(offset: usize, max_offset: usize) -> f32 {
+    if max_offset == 0 {
+        0.0
+    } else {
+        (offset as f64 / max_offset as f64).clamp(0.0, 1.0) as f32
+    }
+}
+
+/// Offset for a fraction of max_offset.
+fn percent_offset(percent: f32, max_offset: usize) -> usize {
+    let percent = if percent.is_nan() {
+        0.0
+    } else {
+        percent.clamp(0.0, 1.0)
+    };
+    if percent >= 1.0 {
+        max_offset
+    } else {
+        min(
+            (percent as f64 * max_offset as f64).round() as usize,
+            max_offset,
+        )
     }
-    ScrollOutcome::NotUsed
 }
 
-fn forward_filter<W, Q, R>(
+/// Start and length of the scrollbar thumb within the track.
+///
+/// This mirrors the calculation of ratatui's Scrollbar, with
+/// max_offset as content_length and page_len as viewport_content_length.
+fn thumb_bounds(max_offset: usize, offset: usize, page_len: usize, track_len: u16) -> (u16, u16) {
+    if track_len == 0 {
+        return (0, 0);
+    }
+    let page_len = if page_len == 0 {
+        track_len as usize
+    } else {
+        page_len
+    };
+
+    let track_len = track_len as f64;
+    let max_position = max_offset.saturating_sub(1) as f64;
+    let start_position = (offset as f64).clamp(0.0, max_position);
+    let max_viewport_position = max_position + page_len as f64;
+    let end_position = start_position + page_len as f64;
+
+    let thumb_start = start_position * track_len / max_viewport_position;
+    let thumb_end = end_position * track_len / max_viewport_position;
+
+    let thumb_start = thumb_start.round().clamp(0.0, track_len - 1.0) as u16;
+    let thumb_end = thumb_end.round().clamp(0.0, track_len) as u16;
+
+    (thumb_start, thumb_end.saturating_sub(thumb_start).max(1))
+}
+
+fn forward_filter<E, W, Q, R>(
     widget: &mut ScrolledState<W>,
-    event: &crossterm::event::Event,
+    event: &E,
     qualifier: Q,
 ) -> ScrollOutcome<R>
 where
-    W: ScrollingState + HandleEvent<crossterm::event::Event, Q, R>,
+    E: ScrollEvent,
+    W: ScrollingState + HandleEvent<E, Q, R>,
     R: ConsumedEvent,
 {
-    let r = match event {
+    let r = match event.scroll_input(true) {
         // these are the events where the scrolled widget might
         // compete with the widget. these are only forwarded if
         // inside the view area.
-        ct_event!(mouse down Left for column, row)
-        | ct_event!(scroll down for column, row)
-        | ct_event!(scroll up for column, row)
-        | ct_event!(scroll ALT down for column, row)
-        | ct_event!(scroll ALT up for column, row) => {
-            if widget.view_area.contains(Position::new(*column, *row)) {
+        Some(ScrollInput::Press(pos))
+        | Some(ScrollInput::ScrollDown(pos))
+        | Some(ScrollInput::ScrollUp(pos))
+        | Some(ScrollInput::ScrollPageDown(pos))
+        | Some(ScrollInput::ScrollPageUp(pos))
+        | Some(ScrollInput::ScrollRight(pos))
+        | Some(ScrollInput::ScrollLeft(pos))
+        | Some(ScrollInput::ScrollBy { pos, .. }) => {
+            if widget.view_area.contains(pos) {
                 ScrollOutcome::Inner(widget.widget.handle(event, qualifier))
             } else {
                 ScrollOutcome::NotUsed
@@ -871,3 +2777,639 @@ where
     };
     r
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct MockState {
+        offset: usize,
+        max_offset: usize,
+        page: usize,
+    }
+
+    impl ScrollingState for MockState {
+        fn vertical_max_offset(&self) -> usize {
+            self.max_offset
+        }
+
+        fn vertical_offset(&self) -> usize {
+            self.offset
+        }
+
+        fn vertical_page(&self) -> usize {
+            self.page
+        }
+
+        fn horizontal_max_offset(&self) -> usize {
+            0
+        }
+
+        fn horizontal_offset(&self) -> usize {
+            0
+        }
+
+        fn horizontal_page(&self) -> usize {
+            0
+        }
+
+        fn set_vertical_offset(&mut self, offset: usize) -> bool {
+            let old_offset = self.offset;
+            self.offset = offset;
+            old_offset != self.offset
+        }
+
+        fn set_horizontal_offset(&mut self, _offset: usize) -> bool {
+            false
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct MockWidget {
+        width: u16,
+        height: u16,
+    }
+
+    impl ScrollingWidget<MockState> for MockWidget {
+        fn need_scroll(&self, area: Rect, _state: &mut MockState) -> (bool, bool) {
+            (area.width < self.width, area.height < self.height)
+        }
+    }
+
+    impl StatefulWidget for MockWidget {
+        type State = MockState;
+
+        fn render(self, _area: Rect, _buf: &mut Buffer, _state: &mut Self::State) {}
+    }
+
+    /// Counts the calls to need_scroll.
+    #[derive(Debug, Default)]
+    struct CountWidget {
+        calls: std::cell::Cell<usize>,
+    }
+
+    impl ScrollingWidget<MockState> for CountWidget {
+        fn need_scroll(&self, _area: Rect, _state: &mut MockState) -> (bool, bool) {
+            self.calls.set(self.calls.get() + 1);
+            // no scrollbars, one call per measurement.
+            (false, false)
+        }
+    }
+
+    impl StatefulWidgetRef for CountWidget {
+        type State = MockState;
+
+        fn render_ref(&self, _area: Rect, _buf: &mut Buffer, _state: &mut Self::State) {}
+    }
+
+    #[test]
+    fn show_scrollbars_exact_fit() {
+        let scrolled = ScrolledImpl::default();
+        let area = Rect::new(0, 0, 20, 10);
+        let mut state = MockState::default();
+
+        let widget = MockWidget {
+            width: 20,
+            height: 10,
+        };
+        assert_eq!(
+            show_scrollbars(&scrolled, &widget, area, &mut state),
+            (false, false)
+        );
+
+        // the vertical scrollbar makes the horizontal one necessary.
+        let widget = MockWidget {
+            width: 20,
+            height: 11,
+        };
+        assert_eq!(
+            show_scrollbars(&scrolled, &widget, area, &mut state),
+            (true, true)
+        );
+
+        // and the other way round.
+        let widget = MockWidget {
+            width: 21,
+            height: 10,
+        };
+        assert_eq!(
+            show_scrollbars(&scrolled, &widget, area, &mut state),
+            (true, true)
+        );
+
+        let widget = MockWidget {
+            width: 21,
+            height: 9,
+        };
+        assert_eq!(
+            show_scrollbars(&scrolled, &widget, area, &mut state),
+            (true, false)
+        );
+    }
+
+    #[test]
+    fn track_pos_offset_large() {
+        let max_offset = u32::MAX as usize;
+        assert_eq!(track_pos_offset(0, max_offset, 20, 10), 0);
+        assert_eq!(track_pos_offset(10, max_offset, 20, 10), max_offset);
+        let half = track_pos_offset(5, max_offset, 20, 10);
+        assert!(half > max_offset / 2 - 10 && half < max_offset / 2 + 20);
+        assert_eq!(track_pos_offset(5, 100, 10, 0), 0);
+    }
+
+    #[test]
+    fn click_and_drag_same_offset() {
+        let mut state = ScrolledState::<MockState>::default();
+        state.widget.max_offset = u32::MAX as usize;
+        state.widget.page = 10;
+        state.area = Rect::new(0, 0, 11, 12);
+        state.v_scrollbar_area = Some(Rect::new(10, 0, 1, 12));
+
+        // the thumb is at the top, click below it.
+        let _: ScrollOutcome<()> = state.handle_input(ScrollInput::Press(Position::new(10, 8)));
+        let click_offset = state.widget.offset;
+        assert!(click_offset > 0);
+
+        state.widget.offset = 0;
+        let _: ScrollOutcome<()> = state.handle_input(ScrollInput::Drag(Position::new(10, 8)));
+        assert_eq!(state.widget.offset, click_offset);
+    }
+
+    #[test]
+    fn thumb_bounds_positions() {
+        // 100 items, page of 10, track of 10 cells.
+        assert_eq!(thumb_bounds(90, 0, 10, 10), (0, 1));
+        assert_eq!(thumb_bounds(90, 90, 10, 10), (9, 1));
+        // almost everything visible.
+        assert_eq!(thumb_bounds(1, 0, 20, 10), (0, 10));
+        assert_eq!(thumb_bounds(90, 0, 10, 0), (0, 0));
+    }
+
+    #[test]
+    fn scrollbar_page_len_min_thumb() {
+        assert_eq!(scrollbar_page_len(100, 10, 10, 0), 10);
+        assert_eq!(scrollbar_page_len(100, 0, 10, 0), 10);
+        // min_thumb_len can't be satisfied.
+        assert_eq!(scrollbar_page_len(100, 10, 10, 10), 10);
+
+        let page_len = scrollbar_page_len(100, 10, 10, 3);
+        assert_eq!(page_len, 43);
+        assert_eq!(thumb_bounds(100, 0, page_len, 10), (0, 3));
+
+        // no overflow with a huge max_offset.
+        let page_len = scrollbar_page_len(usize::MAX, 10, 10, 3);
+        assert_eq!(thumb_bounds(usize::MAX, 0, page_len, 10), (0, 3));
+    }
+
+    #[test]
+    fn invert_wheel() {
+        use crate::event::Outcome;
+        use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+
+        let wheel = |kind, modifiers| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column: 2,
+                row: 2,
+                modifiers,
+            })
+        };
+
+        let mut state = ScrolledState::<MockState>::default();
+        state.widget.max_offset = 100;
+        state.widget.page = 10;
+        state.area = Rect::new(0, 0, 11, 12);
+        state.v_wheel_scroll_by = Some(3);
+        state.config.invert_wheel = true;
+        state.widget.offset = 50;
+
+        let r: ScrollOutcome<Outcome> = mouse_handling(
+            &mut state,
+            &wheel(MouseEventKind::ScrollDown, KeyModifiers::NONE),
+            MouseOnly,
+        );
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.widget.offset, 47);
+
+        let r: ScrollOutcome<Outcome> = mouse_handling(
+            &mut state,
+            &wheel(MouseEventKind::ScrollUp, KeyModifiers::NONE),
+            MouseOnly,
+        );
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.widget.offset, 50);
+
+        // ALT+wheel is horizontal, MockState can't scroll that way.
+        let alt_down = wheel(MouseEventKind::ScrollDown, KeyModifiers::ALT);
+        let r: ScrollOutcome<Outcome> = mouse_handling(&mut state, &alt_down, MouseOnly);
+        assert_eq!(r, ScrollOutcome::AtStart);
+        state.config.no_alt_scroll = true;
+        let r: ScrollOutcome<Outcome> = mouse_handling(&mut state, &alt_down, MouseOnly);
+        assert_eq!(r, ScrollOutcome::NotUsed);
+        assert_eq!(state.widget.offset, 50);
+    }
+
+    #[test]
+    fn cache_need_scroll() {
+        let area = Rect::new(0, 0, 10, 10);
+        let mut buf = Buffer::empty(area);
+        let mut state = ScrolledState::<MockState>::default();
+
+        let scrolled = Scrolled::new(CountWidget::default()).cache_need_scroll(true);
+        scrolled.render_ref(area, &mut buf, &mut state);
+        scrolled.render_ref(area, &mut buf, &mut state);
+        assert_eq!(scrolled.widget.calls.get(), 1);
+
+        // a new area measures again.
+        let area = Rect::new(0, 0, 8, 10);
+        scrolled.render_ref(area, &mut buf, &mut state);
+        assert_eq!(scrolled.widget.calls.get(), 2);
+
+        state.invalidate();
+        scrolled.render_ref(area, &mut buf, &mut state);
+        scrolled.render_ref(area, &mut buf, &mut state);
+        assert_eq!(scrolled.widget.calls.get(), 3);
+
+        // without the cache every render measures.
+        let scrolled = Scrolled::new(CountWidget::default());
+        scrolled.render_ref(area, &mut buf, &mut state);
+        scrolled.render_ref(area, &mut buf, &mut state);
+        assert_eq!(scrolled.widget.calls.get(), 2);
+    }
+
+    #[test]
+    fn modal_over_list() {
+        use crate::event::{flow, Outcome};
+        use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+
+        fn wheel_down(column: u16, row: u16) -> Event {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::ScrollDown,
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            })
+        }
+
+        // the topmost widget gets the event first.
+        fn handle(
+            modal: &mut ScrolledState<MockState>,
+            list: &mut ScrolledState<MockState>,
+            event: &Event,
+        ) -> ScrollOutcome<Outcome> {
+            flow!(mouse_handling(modal, event, MouseOnly));
+            mouse_handling(list, event, MouseOnly)
+        }
+
+        let mut list = ScrolledState::<MockState>::default();
+        list.area = Rect::new(0, 0, 20, 20);
+        list.widget.max_offset = 100;
+        list.widget.page = 20;
+        let mut modal = ScrolledState::<MockState>::default();
+        modal.area = Rect::new(5, 5, 10, 10);
+        modal.widget.max_offset = 2;
+        modal.widget.page = 10;
+
+        assert_eq!(
+            handle(&mut modal, &mut list, &wheel_down(7, 7)),
+            ScrollOutcome::Changed
+        );
+        assert_eq!(modal.widget.offset, 1);
+        assert_eq!(list.widget.offset, 0);
+
+        // the modal is at its end, but still consumes the event.
+        modal.widget.offset = 2;
+        assert_eq!(
+            handle(&mut modal, &mut list, &wheel_down(7, 7)),
+            ScrollOutcome::AtEnd
+        );
+        assert_eq!(modal.widget.offset, 2);
+        assert_eq!(list.widget.offset, 0);
+
+        // outside the modal the list scrolls.
+        assert_eq!(
+            handle(&mut modal, &mut list, &wheel_down(1, 1)),
+            ScrollOutcome::Changed
+        );
+        assert_eq!(modal.widget.offset, 2);
+        assert_eq!(list.widget.offset, 2);
+    }
+
+    #[test]
+    fn scrollbar_positions() {
+        use HScrollPosition::{Bottom, Top};
+        use VScrollPosition::{Left, Right};
+
+        let area = Rect::new(0, 0, 10, 8);
+        let widget = MockWidget {
+            width: 20,
+            height: 20,
+        };
+
+        // (v_pos, h_pos, block, v_scrollbar_area, h_scrollbar_area, corner_area)
+        let cases = [
+            (Right, Bottom, false, (9, 0, 1, 7), (0, 7, 9, 1), (9, 7)),
+            (Right, Top, false, (9, 1, 1, 7), (0, 0, 9, 1), (9, 0)),
+            (Left, Bottom, false, (0, 0, 1, 7), (1, 7, 9, 1), (0, 7)),
+            (Left, Top, false, (0, 1, 1, 7), (1, 0, 9, 1), (0, 0)),
+            // with a block the scrollbars replace the border.
+            (Right, Bottom, true, (9, 1, 1, 6), (1, 7, 8, 1), (9, 7)),
+            (Right, Top, true, (9, 1, 1, 7), (1, 0, 8, 1), (9, 0)),
+            (Left, Bottom, true, (0, 1, 1, 6), (1, 7, 9, 1), (0, 7)),
+            (Left, Top, true, (0, 1, 1, 7), (1, 0, 9, 1), (0, 0)),
+        ];
+        for (v_pos, h_pos, block, v, h, corner) in cases {
+            let mut buf = Buffer::empty(area);
+            let mut state = ScrolledState::<MockState>::default();
+            let mut scrolled = Scrolled::new(widget)
+                .vertical_scroll_position(v_pos)
+                .horizontal_scroll_position(h_pos);
+            if block {
+                scrolled = scrolled.block(Block::bordered());
+            }
+            scrolled.render(area, &mut buf, &mut state);
+
+            let case = format!("{:?} {:?} block={}", v_pos, h_pos, block);
+            assert_eq!(
+                state.v_scrollbar_area,
+                Some(Rect::new(v.0, v.1, v.2, v.3)),
+                "{}",
+                case
+            );
+            assert_eq!(
+                state.h_scrollbar_area,
+                Some(Rect::new(h.0, h.1, h.2, h.3)),
+                "{}",
+                case
+            );
+            assert_eq!(
+                state.corner_area,
+                Some(Rect::new(corner.0, corner.1, 1, 1)),
+                "{}",
+                case
+            );
+        }
+    }
+
+    #[test]
+    fn corner_cell() {
+        use ratatui::style::Color;
+        use ratatui::widgets::Paragraph;
+
+        let area = Rect::new(0, 0, 10, 8);
+        let widget = MockWidget {
+            width: 20,
+            height: 20,
+        };
+        let track_style = Style::new().bg(Color::Blue);
+        let corner_style = Style::new().bg(Color::Red);
+
+        // stale content in the corner.
+        let mut buf = Buffer::empty(area);
+        buf.get_mut(9, 7).set_symbol("x");
+
+        // default is a blank with the track style.
+        let mut state = ScrolledState::<MockState>::default();
+        Scrolled::new(widget)
+            .track_style(track_style)
+            .render(area, &mut buf, &mut state);
+        assert_eq!(state.corner_area, Some(Rect::new(9, 7, 1, 1)));
+        assert_eq!(buf.get(9, 7).symbol(), " ");
+        assert_eq!(buf.get(9, 7).bg, Color::Blue);
+
+        Scrolled::new(widget)
+            .track_style(track_style)
+            .corner_symbol("+")
+            .corner_style(corner_style)
+            .render(area, &mut buf, &mut state);
+        assert_eq!(buf.get(9, 7).symbol(), "+");
+        assert_eq!(buf.get(9, 7).bg, Color::Red);
+
+        let grip = Paragraph::new("#");
+        Scrolled::new(widget)
+            .corner_widget(&grip)
+            .render(area, &mut buf, &mut state);
+        assert_eq!(buf.get(9, 7).symbol(), "#");
+
+        // no corner with only one scrollbar.
+        let mut buf = Buffer::empty(area);
+        let widget = MockWidget {
+            width: 5,
+            height: 20,
+        };
+        Scrolled::new(widget)
+            .corner_symbol("+")
+            .render(area, &mut buf, &mut state);
+        assert_eq!(state.corner_area, None);
+        assert_ne!(buf.get(9, 7).symbol(), "+");
+    }
+
+    #[test]
+    fn scrollbar_margins() {
+        let area = Rect::new(0, 0, 10, 8);
+        let mut buf = Buffer::empty(area);
+        let mut state = ScrolledState::<MockState>::default();
+
+        let vertical = MockWidget {
+            width: 5,
+            height: 20,
+        };
+        Scrolled::new(vertical).render(area, &mut buf, &mut state);
+        assert_eq!(state.v_scrollbar_area, Some(Rect::new(9, 0, 1, 8)));
+        Scrolled::new(vertical)
+            .start_margin(1)
+            .end_margin(2)
+            .render(area, &mut buf, &mut state);
+        assert_eq!(state.v_scrollbar_area, Some(Rect::new(9, 1, 1, 5)));
+
+        let horizontal = MockWidget {
+            width: 20,
+            height: 5,
+        };
+        Scrolled::new(horizontal)
+            .start_margin(1)
+            .end_margin(2)
+            .render(area, &mut buf, &mut state);
+        assert_eq!(state.h_scrollbar_area, Some(Rect::new(1, 7, 7, 1)));
+
+        // margins larger than the scrollbar leave nothing.
+        Scrolled::new(vertical)
+            .start_margin(5)
+            .end_margin(5)
+            .render(area, &mut buf, &mut state);
+        assert_eq!(state.v_scrollbar_area.map(|v| v.height), Some(0));
+    }
+
+    #[test]
+    fn arrow_click() {
+        let mut state = ScrolledState::<MockState>::default();
+        state.widget.max_offset = 100;
+        state.widget.page = 30;
+        state.area = Rect::new(0, 0, 11, 12);
+        state.v_scrollbar_area = Some(Rect::new(10, 0, 1, 12));
+        state.widget.offset = 50;
+        let step = state.widget.vertical_scroll();
+        assert_eq!(step, 3);
+
+        let r: ScrollOutcome<()> = state.handle_input(ScrollInput::Press(Position::new(10, 0)));
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.widget.offset, 50 - step);
+
+        let r: ScrollOutcome<()> = state.handle_input(ScrollInput::Press(Position::new(10, 11)));
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.widget.offset, 50);
+
+        state.widget.offset = 0;
+        let r: ScrollOutcome<()> = state.handle_input(ScrollInput::Press(Position::new(10, 0)));
+        assert_eq!(r, ScrollOutcome::Unchanged);
+        assert_eq!(state.widget.offset, 0);
+    }
+
+    #[test]
+    fn arrow_modes() {
+        let area = Rect::new(0, 0, 10, 8);
+        let widget = MockWidget {
+            width: 5,
+            height: 20,
+        };
+
+        let mut buf = Buffer::empty(area);
+        let mut state = ScrolledState::<MockState>::default();
+        state.widget.max_offset = 100;
+        state.widget.page = 10;
+        Scrolled::new(widget).render(area, &mut buf, &mut state);
+        assert_eq!(buf.get(9, 0).symbol(), "▲");
+        assert_eq!(buf.get(9, 7).symbol(), "▼");
+
+        let mut buf = Buffer::empty(area);
+        Scrolled::new(widget)
+            .arrows(false)
+            .render(area, &mut buf, &mut state);
+        assert_ne!(buf.get(9, 0).symbol(), "▲");
+        assert_ne!(buf.get(9, 7).symbol(), "▼");
+
+        // without arrows the first cell is part of the track,
+        // a click there goes to the start.
+        state.widget.offset = 50;
+        let r: ScrollOutcome<()> = state.handle_input(ScrollInput::Press(Position::new(9, 0)));
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.widget.offset, 0);
+
+        // with arrows it scrolls by one step.
+        Scrolled::new(widget).render(area, &mut buf, &mut state);
+        state.widget.offset = 50;
+        let r: ScrollOutcome<()> = state.handle_input(ScrollInput::Press(Position::new(9, 0)));
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.widget.offset, 49);
+    }
+
+    #[test]
+    fn visible_range_last_page() {
+        // 100 items, page of 10.
+        assert_eq!(visible_range(0, 10, 90), 0..10);
+        assert_eq!(visible_range(90, 10, 90), 90..100);
+        // overscroll, the last page is shorter.
+        assert_eq!(visible_range(95, 10, 90), 95..100);
+        assert_eq!(visible_range(100, 10, 90), 100..100);
+        assert_eq!(visible_range(120, 10, 90), 100..100);
+        // no scrolling, the range is the page.
+        assert_eq!(visible_range(0, 10, 0), 0..10);
+        assert_eq!(
+            visible_range(usize::MAX, 10, usize::MAX),
+            usize::MAX..usize::MAX
+        );
+
+        let mut state = ScrolledState::<MockState>::default();
+        state.widget.max_offset = 90;
+        state.widget.page = 10;
+        state.v_overscroll = 5;
+        state.widget.offset = 95;
+        assert_eq!(state.vertical_visible(), 95..100);
+        assert_eq!(state.vertical_visible().len(), 5);
+    }
+
+    #[test]
+    fn overscroll_symmetric() {
+        let mut state = ScrolledState::<MockState>::default();
+        state.widget.max_offset = 20;
+        state.widget.page = 10;
+        state.area = Rect::new(0, 0, 11, 12);
+        state.v_overscroll = 5;
+        state.v_wheel_scroll_by = Some(4);
+
+        // scroll down to max_offset + overscroll and back up to 0.
+        let pos = Position::new(2, 2);
+        let mut last = None;
+        for _ in 0..10 {
+            last = Some(state.handle_input::<()>(ScrollInput::ScrollDown(pos)));
+        }
+        assert_eq!(last, Some(ScrollOutcome::AtEnd));
+        assert_eq!(state.widget.offset, 25);
+
+        let mut last = None;
+        for _ in 0..10 {
+            last = Some(state.handle_input::<()>(ScrollInput::ScrollUp(pos)));
+        }
+        assert_eq!(last, Some(ScrollOutcome::AtStart));
+        assert_eq!(state.widget.offset, 0);
+
+        // the same with the methods.
+        assert!(state.scroll_down(100));
+        assert_eq!(state.widget.offset, 25);
+        assert!(!state.scroll_down(1));
+        assert!(state.scroll_up(100));
+        assert_eq!(state.widget.offset, 0);
+        assert!(!state.scroll_up(1));
+    }
+
+    #[test]
+    fn scroll_by_large_deltas() {
+        let pos = Position::new(2, 2);
+        let mut state = ScrolledState::<MockState>::default();
+        state.widget.max_offset = 1000;
+        state.widget.page = 10;
+        state.area = Rect::new(0, 0, 11, 12);
+        state.v_wheel_scroll_by = Some(3);
+        state.widget.offset = 500;
+
+        let by = |h, v| ScrollInput::ScrollBy { pos, h, v };
+
+        let r: ScrollOutcome<()> = state.handle_input(by(0, i16::MAX));
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.widget.offset, 1000);
+        let r: ScrollOutcome<()> = state.handle_input(by(0, i16::MAX));
+        assert_eq!(r, ScrollOutcome::Unchanged);
+        assert_eq!(state.widget.offset, 1000);
+
+        let r: ScrollOutcome<()> = state.handle_input(by(i16::MIN, i16::MIN));
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.widget.offset, 0);
+        let r: ScrollOutcome<()> = state.handle_input(by(i16::MIN, i16::MIN));
+        assert_eq!(r, ScrollOutcome::Unchanged);
+
+        // no overflow of the wheel step.
+        state.widget.max_offset = usize::MAX;
+        state.v_overscroll = 10;
+        state.v_wheel_scroll_by = Some(usize::MAX / 2);
+        let r: ScrollOutcome<()> = state.handle_input(by(0, i16::MAX));
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.widget.offset, usize::MAX);
+        let r: ScrollOutcome<()> = state.handle_input(by(0, i16::MIN));
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.widget.offset, 0);
+
+        // the same with the signed methods.
+        assert!(state.scroll_vertical_by(isize::MAX));
+        assert_eq!(state.widget.offset, isize::MAX as usize);
+        assert!(state.scroll_vertical_by(isize::MAX));
+        assert!(state.scroll_vertical_by(isize::MAX));
+        assert_eq!(state.widget.offset, usize::MAX);
+        assert!(!state.scroll_vertical_by(1));
+        assert!(state.scroll_vertical_by(isize::MIN));
+        assert_eq!(state.widget.offset, usize::MAX - isize::MIN.unsigned_abs());
+        assert!(state.scroll_vertical_by(isize::MIN));
+        assert_eq!(state.widget.offset, 0);
+        assert!(!state.scroll_vertical_by(-1));
+    }
+}
@@ -0,0 +1,71 @@
+//!
+//! Symbol presets for the scrollbars.
+//!
+//! Use with [Scrolled::v_symbols](crate::Scrolled::v_symbols),
+//! [Scrolled::h_symbols](crate::Scrolled::h_symbols),
+//! [Scrolled::v_no_symbol](crate::Scrolled::v_no_symbol) and
+//! [Scrolled::h_no_symbol](crate::Scrolled::h_no_symbol).
+//!
+//! The ASCII presets are a fallback for terminals that don't
+//! render the box drawing characters well.
+//!
+use ratatui::symbols::scrollbar::Set;
+
+pub use ratatui::symbols::scrollbar::{DOUBLE_HORIZONTAL, DOUBLE_VERTICAL, HORIZONTAL, VERTICAL};
+
+/// Only ASCII characters.
+pub const ASCII_VERTICAL: Set = Set {
+    track: "|",
+    thumb: "#",
+    begin: "^",
+    end: "v",
+};
+
+/// Only ASCII characters.
+pub const ASCII_HORIZONTAL: Set = Set {
+    track: "-",
+    thumb: "#",
+    begin: "<",
+    end: ">",
+};
+
+/// Shaded track with a full block thumb.
+pub const BLOCK_VERTICAL: Set = Set {
+    track: "░",
+    thumb: "█",
+    begin: "▲",
+    end: "▼",
+};
+
+/// Shaded track with a full block thumb.
+pub const BLOCK_HORIZONTAL: Set = Set {
+    track: "░",
+    thumb: "█",
+    begin: "◄",
+    end: "►",
+};
+
+/// Thin lines with arrows.
+pub const ARROW_VERTICAL: Set = Set {
+    track: "│",
+    thumb: "┃",
+    begin: "↑",
+    end: "↓",
+};
+
+/// Thin lines with arrows.
+pub const ARROW_HORIZONTAL: Set = Set {
+    track: "─",
+    thumb: "━",
+    begin: "←",
+    end: "→",
+};
+
+/// Fills a vertical scrollbar with ScrollbarType::Minimal.
+pub const NO_SYMBOL_VERTICAL: &str = "┊";
+
+/// Fills a horizontal scrollbar with ScrollbarType::Minimal.
+pub const NO_SYMBOL_HORIZONTAL: &str = "┈";
+
+/// Fills a scrollbar with ScrollbarType::Minimal. Only ASCII.
+pub const NO_SYMBOL_ASCII: &str = ".";
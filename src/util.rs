@@ -1,49 +1,183 @@
+use crate::view::VerticalAlignment;
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Position, Rect};
+use ratatui::layout::{Alignment, Rect};
 use ratatui::style::Style;
+use unicode_width::UnicodeWidthStr;
 
 /// Copy a tmp buffer to another buf.
-/// The tmp-buffer is offset by h_offset/v_offset.
+/// The tmp-buffer is offset by h_offset/v_offset. A negative
+/// offset moves the content right/down.
 /// Any outside area is cleared and set to empty_style.
-/// Everything is clipped to the target area.
+/// Everything is clipped to the target area. Wide characters
+/// that are cut by the clipping are replaced with an empty cell.
+///
+/// The position of the tmp-buffer is taken from its own area,
+/// relative to the origin of view_area.
 pub(crate) fn copy_buffer(
     view_area: Rect,
-    mut tmp: Buffer,
-    v_offset: usize,
-    h_offset: usize,
+    tmp: &Buffer,
+    v_offset: isize,
+    h_offset: isize,
     empty_style: Style,
     area: Rect,
     buf: &mut Buffer,
 ) {
+    // origin of the tmp-buffer relative to the view.
+    let tmp_y = tmp.area.y as isize - view_area.y as isize;
+    let tmp_x = tmp.area.x as isize - view_area.x as isize;
+
     // copy buffer
-    for (cell_offset, cell) in tmp.content.drain(..).enumerate() {
-        let tmp_row = cell_offset as u16 / tmp.area.width;
-        let tmp_col = cell_offset as u16 % tmp.area.width;
+    let tmp_width = tmp.area.width as usize;
+    for (cell_offset, cell) in tmp.content.iter().enumerate() {
+        let tmp_row = (cell_offset / tmp_width) as isize;
+        let tmp_col = (cell_offset % tmp_width) as isize;
+
+        let row = area.y as isize + tmp_y + tmp_row - v_offset;
+        let col = area.x as isize + tmp_x + tmp_col - h_offset;
 
-        if area.y + tmp_row >= v_offset as u16 && area.x + tmp_col >= h_offset as u16 {
-            let row = area.y + tmp_row - v_offset as u16;
-            let col = area.x + tmp_col - h_offset as u16;
+        if row >= area.y as isize
+            && row < area.bottom() as isize
+            && col >= area.x as isize
+            && col < area.right() as isize
+        {
+            let cell_width = cell.symbol().width() as isize;
+            // a wide character cut at the left or right border
+            // is replaced with an empty cell.
+            let cut_left = col == area.x as isize
+                && (1..=tmp_col.min(3)).any(|back| {
+                    let prev = &tmp.content[cell_offset - back as usize];
+                    prev.symbol().width() as isize > back
+                });
+            let cut_right = col + cell_width > area.right() as isize;
 
-            if area.contains(Position::new(col, row)) {
-                *buf.get_mut(col, row) = cell;
+            let target = buf.get_mut(col as u16, row as u16);
+            if cut_left || cut_right {
+                target.reset();
+                target.set_style(empty_style);
             } else {
-                // clip
+                *target = cell.clone();
             }
         } else {
-            // clip2
+            // clip
         }
     }
 
     // clear the rest
-    let filled_left = (area.x + view_area.width).saturating_sub(h_offset as u16);
-    let filled_bottom = (area.y + view_area.height).saturating_sub(v_offset as u16);
+    let filled_left = area.x as isize + tmp_x - h_offset;
+    let filled_right = filled_left + tmp.area.width as isize;
+    let filled_top = area.y as isize + tmp_y - v_offset;
+    let filled_bottom = filled_top + tmp.area.height as isize;
 
     for r in area.y..area.y + area.height {
         for c in area.x..area.x + area.width {
-            if c >= filled_left || r >= filled_bottom {
+            let (rr, cc) = (r as isize, c as isize);
+            if cc < filled_left || cc >= filled_right || rr < filled_top || rr >= filled_bottom {
                 buf.get_mut(c, r).reset();
                 buf.get_mut(c, r).set_style(empty_style);
             }
         }
     }
 }
+
+/// Horizontal offset for copy_buffer.
+///
+/// If the content fits the area, the content is aligned.
+/// The offset is only used for the default alignment.
+pub(crate) fn h_align_offset(offset: usize, content: u16, area: u16, align: Alignment) -> isize {
+    if content < area {
+        let free = (area - content) as isize;
+        match align {
+            Alignment::Left => offset as isize,
+            Alignment::Center => -(free / 2),
+            Alignment::Right => -free,
+        }
+    } else {
+        offset as isize
+    }
+}
+
+/// Vertical offset for copy_buffer.
+///
+/// If the content fits the area, the content is aligned.
+/// The offset is only used for the default alignment.
+pub(crate) fn v_align_offset(
+    offset: usize,
+    content: u16,
+    area: u16,
+    align: VerticalAlignment,
+) -> isize {
+    if content < area {
+        let free = (area - content) as isize;
+        match align {
+            VerticalAlignment::Top => offset as isize,
+            VerticalAlignment::Center => -(free / 2),
+            VerticalAlignment::Bottom => -free,
+        }
+    } else {
+        offset as isize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tmp_buffer(area: Rect, text: &str) -> Buffer {
+        let mut tmp = Buffer::empty(area);
+        tmp.set_string(area.x, area.y, text, Style::default());
+        tmp
+    }
+
+    #[test]
+    fn wide_char_cut_left() {
+        let view_area = Rect::new(0, 0, 4, 1);
+        let tmp = tmp_buffer(view_area, "a世b");
+
+        let area = Rect::new(0, 0, 4, 1);
+        let mut buf = Buffer::empty(area);
+        buf.set_string(0, 0, "xxxx", Style::default());
+
+        // fully visible.
+        copy_buffer(view_area, &tmp, 0, 1, Style::default(), area, &mut buf);
+        assert_eq!(buf.get(0, 0).symbol(), "世");
+
+        // the second half of the wide char is at the left border.
+        copy_buffer(view_area, &tmp, 0, 2, Style::default(), area, &mut buf);
+        assert_eq!(buf.get(0, 0).symbol(), " ");
+        assert_eq!(buf.get(1, 0).symbol(), "b");
+    }
+
+    #[test]
+    fn wide_char_cut_right() {
+        let view_area = Rect::new(0, 0, 4, 1);
+        let tmp = tmp_buffer(view_area, "a世b");
+
+        let area = Rect::new(0, 0, 2, 1);
+        let mut buf = Buffer::empty(area);
+        buf.set_string(0, 0, "xx", Style::default());
+
+        copy_buffer(view_area, &tmp, 0, 0, Style::default(), area, &mut buf);
+        assert_eq!(buf.get(0, 0).symbol(), "a");
+        assert_eq!(buf.get(1, 0).symbol(), " ");
+    }
+
+    #[test]
+    fn tmp_area_offset() {
+        let view_area = Rect::new(0, 0, 4, 2);
+        // tmp-buffer covers only part of the view.
+        let tmp = tmp_buffer(Rect::new(1, 1, 2, 1), "xy");
+
+        let area = Rect::new(10, 10, 4, 2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+        buf.set_string(10, 10, "oooo", Style::default());
+        buf.set_string(10, 11, "oooo", Style::default());
+
+        copy_buffer(view_area, &tmp, 0, 0, Style::default(), area, &mut buf);
+        assert_eq!(buf.get(11, 11).symbol(), "x");
+        assert_eq!(buf.get(12, 11).symbol(), "y");
+        // outside of the tmp-buffer is cleared.
+        assert_eq!(buf.get(10, 10).symbol(), " ");
+        assert_eq!(buf.get(10, 11).symbol(), " ");
+        assert_eq!(buf.get(13, 11).symbol(), " ");
+    }
+}
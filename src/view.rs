@@ -9,14 +9,18 @@
 use crate::_private::NonExhaustive;
 use crate::event::ScrollOutcome;
 use crate::inner::{InnerOwned, InnerRef, InnerWidget};
-use crate::util::copy_buffer;
-use crate::{ScrollingState, ScrollingWidget};
-use rat_event::{ConsumedEvent, HandleEvent};
+use crate::util::{copy_buffer, h_align_offset, v_align_offset};
+use crate::{ScrollEvent, ScrollInput, ScrollingState, ScrollingWidget};
+use log::warn;
+use rat_event::{ConsumedEvent, FocusKeys, HandleEvent, MouseOnly};
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Rect, Size};
+use ratatui::layout::{Alignment, Rect, Size};
 use ratatui::prelude::{StatefulWidget, Widget};
 use ratatui::style::Style;
 use ratatui::widgets::{StatefulWidgetRef, WidgetRef};
+use std::cmp::min;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 
 /// View has its own size, and can contain a stateless widget
 /// that will be rendered to a view sized buffer.
@@ -34,23 +38,79 @@ struct ViewImpl {
     /// Size of the view. The widget is drawn to a separate buffer
     /// with this size. x and y are set to the rendering area.
     view_size: Size,
+    /// Calculates the view_size from the render area.
+    view_size_fn: Option<ViewSizeFn>,
     /// Style for any area outside the contained widget.
     style: Style,
+    /// Alignment of content smaller than the area.
+    h_align: Alignment,
+    /// Alignment of content smaller than the area.
+    v_align: VerticalAlignment,
+    /// Reuse the rendered buffer until invalidated.
+    cached: bool,
+}
+
+/// Calculates the size of the view from the area.
+#[derive(Clone)]
+pub(crate) struct ViewSizeFn(pub(crate) Arc<dyn Fn(Rect) -> Size + Send + Sync>);
+
+impl Debug for ViewSizeFn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ViewSizeFn")
+    }
+}
+
+impl ViewImpl {
+    /// Size of the view for the given area.
+    fn view_size(&self, area: Rect) -> Size {
+        if let Some(view_size_fn) = &self.view_size_fn {
+            (view_size_fn.0)(area)
+        } else {
+            self.view_size
+        }
+    }
+}
+
+/// Vertical alignment of the content.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VerticalAlignment {
+    #[default]
+    Top,
+    Center,
+    Bottom,
 }
 
 /// State of the view.
-#[derive(Debug, Clone)]
+///
+/// With the feature `serde` the offsets can be persisted.
+/// The content may have changed when the state is restored,
+/// so the offsets should be limited again with
+/// set_vertical_offset()/set_horizontal_offset() after the
+/// first render.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ViewState {
     /// The drawing area for the view.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub area: Rect,
     /// The view area that the inner widget sees.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub view_area: Rect,
     /// Horizontal offset
     pub h_offset: usize,
     /// Vertical offset
     pub v_offset: usize,
 
+    /// Temp buffer for rendering the widget.
+    /// Kept between frames to avoid reallocation.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    buffer: Buffer,
+    /// The buffer must be rendered again.
+    /// Only used with [View::cached].
+    #[cfg_attr(feature = "serde", serde(skip, default = "dirty_default"))]
+    dirty: bool,
+
     /// Only construct with `..Default::default()`.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub non_exhaustive: NonExhaustive,
 }
 
@@ -69,11 +129,46 @@ impl<T> View<T> {
         self
     }
 
+    /// Calculate the size for the inner widget from the area
+    /// with each render.
+    ///
+    /// Use this if the size of the content depends on the
+    /// available area, e.g. a wrapped Paragraph. This overrides
+    /// [View::view_size].
+    pub fn view_size_fn(
+        mut self,
+        view_size_fn: impl Fn(Rect) -> Size + Send + Sync + 'static,
+    ) -> Self {
+        self.view.view_size_fn = Some(ViewSizeFn(Arc::new(view_size_fn)));
+        self
+    }
+
     /// Style for the empty space outside the rendered buffer.
     pub fn style(mut self, style: Style) -> Self {
         self.view.style = style;
         self
     }
+
+    /// Alignment of the content if it is smaller than the area.
+    ///
+    /// This only applies if there is nothing to scroll in
+    /// that direction.
+    pub fn content_align(mut self, h_align: Alignment, v_align: VerticalAlignment) -> Self {
+        self.view.h_align = h_align;
+        self.view.v_align = v_align;
+        self
+    }
+
+    /// Render the inner widget only once and reuse the result.
+    ///
+    /// Scrolling doesn't need a new render, the last buffer is
+    /// copied with the new offsets. If the content of the widget
+    /// changes, call [ViewState::invalidate]. A change of the
+    /// view size renders again too.
+    pub fn cached(mut self, cached: bool) -> Self {
+        self.view.cached = cached;
+        self
+    }
 }
 
 impl<T> StatefulWidgetRef for View<T>
@@ -110,17 +205,40 @@ fn render_ref<W>(
     state: &mut ViewState,
 ) {
     state.area = area;
-    state.view_area = Rect::new(area.x, area.y, view.view_size.width, view.view_size.height);
+    let view_size = view.view_size(area);
+    state.view_area = Rect::new(area.x, area.y, view_size.width, view_size.height);
+    if (view_size.width == 0 || view_size.height == 0) && !area.is_empty() {
+        warn!("view_size is empty, nothing will be rendered. Set view_size or view_size_fn.");
+    }
+    // the view may have shrunk below the offsets.
+    state.clamp_offsets();
 
-    let mut tmp = Buffer::empty(state.view_area);
+    if !view.cached || state.dirty || state.buffer.area != state.view_area {
+        state.buffer.resize(state.view_area);
+        state.buffer.reset();
 
-    inner.render_inner(state.view_area, &mut tmp, &mut ());
+        inner.render_inner(state.view_area, &mut state.buffer, &mut ());
+        state.dirty = false;
+    }
 
-    copy_buffer(
-        state.view_area,
-        tmp,
+    let v_offset = v_align_offset(
         state.v_offset,
+        state.view_area.height,
+        area.height,
+        view.v_align,
+    );
+    let h_offset = h_align_offset(
         state.h_offset,
+        state.view_area.width,
+        area.width,
+        view.h_align,
+    );
+
+    copy_buffer(
+        state.view_area,
+        &state.buffer,
+        v_offset,
+        h_offset,
         view.style,
         area,
         buf,
@@ -132,10 +250,8 @@ where
     T: Widget,
 {
     fn need_scroll(&self, area: Rect, _state: &mut State) -> (bool, bool) {
-        (
-            area.width < self.view.view_size.width,
-            area.height < self.view.view_size.height,
-        )
+        let view_size = self.view.view_size(area);
+        (area.width < view_size.width, area.height < view_size.height)
     }
 }
 
@@ -146,11 +262,54 @@ impl Default for ViewState {
             view_area: Default::default(),
             h_offset: 0,
             v_offset: 0,
+            buffer: Default::default(),
+            dirty: true,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl Debug for ViewState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // the buffer is only a cache.
+        f.debug_struct("ViewState")
+            .field("area", &self.area)
+            .field("view_area", &self.view_area)
+            .field("h_offset", &self.h_offset)
+            .field("v_offset", &self.v_offset)
+            .field("dirty", &self.dirty)
+            .finish()
+    }
+}
+
+impl Clone for ViewState {
+    fn clone(&self) -> Self {
+        // the clone renders its own buffer.
+        Self {
+            area: self.area,
+            view_area: self.view_area,
+            h_offset: self.h_offset,
+            v_offset: self.v_offset,
+            buffer: Default::default(),
+            dirty: true,
             non_exhaustive: NonExhaustive,
         }
     }
 }
 
+impl ViewState {
+    /// The content of the widget changed, render again.
+    /// Only needed with [View::cached].
+    pub fn invalidate(&mut self) {
+        self.dirty = true;
+    }
+}
+
+#[cfg(feature = "serde")]
+fn dirty_default() -> bool {
+    true
+}
+
 impl ScrollingState for ViewState {
     fn vertical_max_offset(&self) -> usize {
         self.view_area.height.saturating_sub(self.area.height) as usize
@@ -177,38 +336,222 @@ impl ScrollingState for ViewState {
     }
 
     fn set_vertical_offset(&mut self, offset: usize) -> bool {
+        // no overscroll, there is nothing to show beyond the buffer.
         let old_offset = self.v_offset;
-
-        if self.v_offset < self.view_area.height as usize {
-            self.v_offset = offset;
-        } else if self.v_offset >= self.view_area.height as usize {
-            self.v_offset = self.view_area.height.saturating_sub(1) as usize;
-        }
-
+        self.v_offset = min(offset, self.vertical_max_offset());
         old_offset != self.v_offset
     }
 
     fn set_horizontal_offset(&mut self, offset: usize) -> bool {
+        // no overscroll, there is nothing to show beyond the buffer.
         let old_offset = self.h_offset;
+        self.h_offset = min(offset, self.horizontal_max_offset());
+        old_offset != self.h_offset
+    }
+}
 
-        if self.h_offset < self.view_area.width as usize {
-            self.h_offset = offset;
-        } else if self.h_offset >= self.view_area.width as usize {
-            self.h_offset = self.view_area.width.saturating_sub(1) as usize;
-        }
+/// Qualifier for the mouse handling of a [View] that is used
+/// without [Scrolled](crate::Scrolled).
+///
+/// ```rust ignore
+/// view_state.handle(event, ViewMouse)
+/// ```
+///
+/// Inside Scrolled the ScrolledState does the scrolling and forwards
+/// FocusKeys and MouseOnly to the ViewState, which ignores them.
+/// A separate qualifier keeps the two from scrolling the same view.
+#[derive(Debug)]
+pub struct ViewMouse;
 
-        old_offset != self.h_offset
+/// Ignores all events. Scrolling is done by the
+/// [ScrolledState](crate::ScrolledState).
+impl<R> HandleEvent<crossterm::event::Event, FocusKeys, ScrollOutcome<R>> for ViewState
+where
+    R: ConsumedEvent,
+{
+    fn handle(&mut self, _event: &crossterm::event::Event, _keymap: FocusKeys) -> ScrollOutcome<R> {
+        ScrollOutcome::NotUsed
     }
 }
 
-/// Handle all events.
-/// Text events are only processed if focus is true.
-/// Mouse events are processed if they are in range.
-impl<R, Q> HandleEvent<crossterm::event::Event, Q, ScrollOutcome<R>> for ViewState
+/// Ignores all events. Scrolling is done by the
+/// [ScrolledState](crate::ScrolledState).
+impl<R> HandleEvent<crossterm::event::Event, MouseOnly, ScrollOutcome<R>> for ViewState
 where
     R: ConsumedEvent,
 {
-    fn handle(&mut self, _event: &crossterm::event::Event, _keymap: Q) -> ScrollOutcome<R> {
+    fn handle(&mut self, _event: &crossterm::event::Event, _keymap: MouseOnly) -> ScrollOutcome<R> {
         ScrollOutcome::NotUsed
     }
 }
+
+/// Mouse handling for a View without Scrolled.
+/// Wheel events are processed if they are in range.
+impl<R> HandleEvent<crossterm::event::Event, ViewMouse, ScrollOutcome<R>> for ViewState
+where
+    R: ConsumedEvent,
+{
+    fn handle(&mut self, event: &crossterm::event::Event, _keymap: ViewMouse) -> ScrollOutcome<R> {
+        if let Some(input) = event.scroll_input(true) {
+            self.handle_input(input)
+        } else {
+            ScrollOutcome::NotUsed
+        }
+    }
+}
+
+impl ViewState {
+    /// Wheel scrolling for a View used without [Scrolled](crate::Scrolled).
+    ///
+    /// The mouse wheel scrolls the view if the mouse is inside
+    /// the area. This is the ScrollInput part of handling [ViewMouse],
+    /// and can be used with other event sources.
+    pub fn handle_input<R>(&mut self, input: ScrollInput) -> ScrollOutcome<R> {
+        let r = match input {
+            ScrollInput::ScrollUp(pos) if self.area.contains(pos) => {
+                self.scroll_up(self.vertical_scroll())
+            }
+            ScrollInput::ScrollDown(pos) if self.area.contains(pos) => {
+                self.scroll_down(self.vertical_scroll())
+            }
+            ScrollInput::ScrollLeft(pos) if self.area.contains(pos) => {
+                self.scroll_left(self.horizontal_scroll())
+            }
+            ScrollInput::ScrollRight(pos) if self.area.contains(pos) => {
+                self.scroll_right(self.horizontal_scroll())
+            }
+            _ => return ScrollOutcome::NotUsed,
+        };
+        if r {
+            ScrollOutcome::Changed
+        } else {
+            ScrollOutcome::Unchanged
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::Paragraph;
+
+    fn lines(n: usize) -> String {
+        (0..n).map(|v| v.to_string()).collect::<Vec<_>>().join("\n")
+    }
+
+    #[test]
+    fn offset_clamped() {
+        let mut state = ViewState {
+            area: Rect::new(0, 0, 10, 10),
+            view_area: Rect::new(0, 0, 12, 25),
+            ..Default::default()
+        };
+
+        assert!(state.set_vertical_offset(100));
+        assert_eq!(state.vertical_offset(), 15);
+        assert_eq!(state.vertical_offset(), state.vertical_max_offset());
+        assert!(!state.set_vertical_offset(15));
+
+        assert!(state.set_horizontal_offset(100));
+        assert_eq!(state.horizontal_offset(), 2);
+        assert_eq!(state.horizontal_offset(), state.horizontal_max_offset());
+    }
+
+    #[test]
+    fn view_size_shrinks() {
+        let area = Rect::new(0, 0, 10, 5);
+        let mut buf = Buffer::empty(area);
+        let mut state = ViewState::default();
+
+        View::new(Paragraph::new(lines(20)))
+            .view_size(Size::new(10, 20))
+            .render(area, &mut buf, &mut state);
+        state.set_vertical_offset(15);
+        assert_eq!(state.vertical_offset(), 15);
+
+        // the content shrinks below the offset.
+        View::new(Paragraph::new(lines(8)))
+            .view_size(Size::new(10, 8))
+            .render(area, &mut buf, &mut state);
+        assert_eq!(state.vertical_offset(), 3);
+        assert_eq!(state.vertical_max_offset(), 3);
+        assert_eq!(buf.get(0, 0).symbol(), "3");
+        assert_eq!(buf.get(0, 4).symbol(), "7");
+    }
+
+    #[test]
+    fn content_align() {
+        let area = Rect::new(0, 0, 6, 3);
+        let h_aligns = [
+            (Alignment::Left, 0),
+            (Alignment::Center, 2),
+            (Alignment::Right, 4),
+        ];
+        let v_aligns = [
+            (VerticalAlignment::Top, 0),
+            (VerticalAlignment::Center, 1),
+            (VerticalAlignment::Bottom, 2),
+        ];
+
+        for (h_align, x) in h_aligns {
+            for (v_align, y) in v_aligns {
+                let mut buf = Buffer::empty(area);
+                let mut state = ViewState::default();
+                View::new(Paragraph::new("ab"))
+                    .view_size(Size::new(2, 1))
+                    .content_align(h_align, v_align)
+                    .render(area, &mut buf, &mut state);
+
+                let mut expect = Buffer::empty(area);
+                expect.set_string(x, y, "ab", Style::default());
+                assert_eq!(buf, expect, "{:?} {:?}", h_align, v_align);
+            }
+        }
+    }
+
+    #[test]
+    fn view_mouse_wheel() {
+        use crate::event::Outcome;
+        use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+
+        let wheel = |kind, column, row| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        let mut state = ViewState {
+            area: Rect::new(0, 0, 10, 10),
+            view_area: Rect::new(0, 0, 10, 25),
+            ..Default::default()
+        };
+
+        let r: ScrollOutcome<Outcome> =
+            state.handle(&wheel(MouseEventKind::ScrollDown, 2, 2), ViewMouse);
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.vertical_offset(), state.vertical_scroll());
+
+        // outside the area
+        let r: ScrollOutcome<Outcome> =
+            state.handle(&wheel(MouseEventKind::ScrollDown, 12, 2), ViewMouse);
+        assert_eq!(r, ScrollOutcome::NotUsed);
+        assert_eq!(state.vertical_offset(), state.vertical_scroll());
+
+        // the qualifiers forwarded by Scrolled are ignored
+        let r: ScrollOutcome<Outcome> =
+            state.handle(&wheel(MouseEventKind::ScrollDown, 2, 2), MouseOnly);
+        assert_eq!(r, ScrollOutcome::NotUsed);
+        assert_eq!(state.vertical_offset(), state.vertical_scroll());
+
+        let r: ScrollOutcome<Outcome> =
+            state.handle(&wheel(MouseEventKind::ScrollUp, 2, 2), ViewMouse);
+        assert_eq!(r, ScrollOutcome::Changed);
+        assert_eq!(state.vertical_offset(), 0);
+        let r: ScrollOutcome<Outcome> =
+            state.handle(&wheel(MouseEventKind::ScrollUp, 2, 2), ViewMouse);
+        assert_eq!(r, ScrollOutcome::Unchanged);
+    }
+}
@@ -9,14 +9,19 @@
 use crate::_private::NonExhaustive;
 use crate::event::ScrollOutcome;
 use crate::inner::{InnerStatefulOwned, InnerStatefulRef, InnerWidget};
-use crate::util::copy_buffer;
+use crate::util::{copy_buffer, h_align_offset, v_align_offset};
+use crate::view::{VerticalAlignment, ViewSizeFn};
 use crate::{ScrollingState, ScrollingWidget};
+use log::warn;
 use rat_event::{ConsumedEvent, HandleEvent};
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Rect, Size};
+use ratatui::layout::{Alignment, Rect, Size};
 use ratatui::prelude::StatefulWidget;
 use ratatui::style::Style;
 use ratatui::widgets::StatefulWidgetRef;
+use std::cmp::min;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 
 /// View has its own size, and can contain a stateful widget
 /// that will be rendered to a view sized buffer.
@@ -34,12 +39,28 @@ struct ViewportImpl {
     /// Size of the view. The widget is drawn to a separate buffer
     /// with this size. x and y are set to the rendering area.
     view_size: Size,
+    /// Calculates the view_size from the render area.
+    view_size_fn: Option<ViewSizeFn>,
     /// Style for any area outside the contained widget.
     style: Style,
+    /// Alignment of content smaller than the area.
+    h_align: Alignment,
+    /// Alignment of content smaller than the area.
+    v_align: VerticalAlignment,
+}
+
+impl ViewportImpl {
+    /// Size of the view for the given area.
+    fn view_size(&self, area: Rect) -> Size {
+        if let Some(view_size_fn) = &self.view_size_fn {
+            (view_size_fn.0)(area)
+        } else {
+            self.view_size
+        }
+    }
 }
 
 /// State of the viewport.
-#[derive(Debug, Clone)]
 pub struct ViewportState<S> {
     /// Widget state.
     pub widget: S,
@@ -53,6 +74,14 @@ pub struct ViewportState<S> {
     /// Vertical offset
     pub v_offset: usize,
 
+    /// Temp buffer for rendering the widget.
+    /// Kept between frames to avoid reallocation.
+    buffer: Buffer,
+    /// Alignment of the last render.
+    h_align: Alignment,
+    /// Alignment of the last render.
+    v_align: VerticalAlignment,
+
     /// Only construct with `..Default::default()`.
     pub non_exhaustive: NonExhaustive,
 }
@@ -72,11 +101,34 @@ impl<T> Viewport<T> {
         self
     }
 
+    /// Calculate the size for the inner widget from the area
+    /// with each render.
+    ///
+    /// Use this if the size of the content depends on the
+    /// available area. This overrides [Viewport::view_size].
+    pub fn view_size_fn(
+        mut self,
+        view_size_fn: impl Fn(Rect) -> Size + Send + Sync + 'static,
+    ) -> Self {
+        self.viewport.view_size_fn = Some(ViewSizeFn(Arc::new(view_size_fn)));
+        self
+    }
+
     /// Style for the empty space outside the rendered buffer.
     pub fn style(mut self, style: Style) -> Self {
         self.viewport.style = style;
         self
     }
+
+    /// Alignment of the content if it is smaller than the area.
+    ///
+    /// This only applies if there is nothing to scroll in
+    /// that direction.
+    pub fn content_align(mut self, h_align: Alignment, v_align: VerticalAlignment) -> Self {
+        self.viewport.h_align = h_align;
+        self.viewport.v_align = v_align;
+        self
+    }
 }
 
 impl<T> StatefulWidgetRef for Viewport<T>
@@ -113,22 +165,28 @@ fn render_ref<W, S>(
     state: &mut ViewportState<S>,
 ) {
     state.area = area;
-    state.view_area = Rect::new(
-        area.x,
-        area.y,
-        viewport.view_size.width,
-        viewport.view_size.height,
-    );
+    let view_size = viewport.view_size(area);
+    state.view_area = Rect::new(area.x, area.y, view_size.width, view_size.height);
+    if (view_size.width == 0 || view_size.height == 0) && !area.is_empty() {
+        warn!("view_size is empty, nothing will be rendered. Set view_size or view_size_fn.");
+    }
+    // the view may have shrunk below the offsets.
+    state.clamp_offsets();
+
+    state.buffer.resize(state.view_area);
+    state.buffer.reset();
 
-    let mut tmp = Buffer::empty(state.view_area);
+    inner.render_inner(state.view_area, &mut state.buffer, &mut state.widget);
 
-    inner.render_inner(state.view_area, &mut tmp, &mut state.widget);
+    state.h_align = viewport.h_align;
+    state.v_align = viewport.v_align;
+    let (h_offset, v_offset) = state.align_offsets();
 
     copy_buffer(
         state.view_area,
-        tmp,
-        state.v_offset,
-        state.h_offset,
+        &state.buffer,
+        v_offset,
+        h_offset,
         viewport.style,
         area,
         buf,
@@ -140,10 +198,8 @@ where
     T: StatefulWidget,
 {
     fn need_scroll(&self, area: Rect, _state: &mut State) -> (bool, bool) {
-        (
-            area.width < self.viewport.view_size.width,
-            area.height < self.viewport.view_size.height,
-        )
+        let view_size = self.viewport.view_size(area);
+        (area.width < view_size.width, area.height < view_size.height)
     }
 }
 
@@ -155,12 +211,65 @@ impl<S: Default> Default for ViewportState<S> {
             view_area: Default::default(),
             h_offset: 0,
             v_offset: 0,
+            buffer: Default::default(),
+            h_align: Default::default(),
+            v_align: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<S: Debug> Debug for ViewportState<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // the buffer is only a cache.
+        f.debug_struct("ViewportState")
+            .field("widget", &self.widget)
+            .field("area", &self.area)
+            .field("view_area", &self.view_area)
+            .field("h_offset", &self.h_offset)
+            .field("v_offset", &self.v_offset)
+            .field("h_align", &self.h_align)
+            .field("v_align", &self.v_align)
+            .finish()
+    }
+}
+
+impl<S: Clone> Clone for ViewportState<S> {
+    fn clone(&self) -> Self {
+        // the buffer is rendered anew each frame.
+        Self {
+            widget: self.widget.clone(),
+            area: self.area,
+            view_area: self.view_area,
+            h_offset: self.h_offset,
+            v_offset: self.v_offset,
+            buffer: Default::default(),
+            h_align: self.h_align,
+            v_align: self.v_align,
             non_exhaustive: NonExhaustive,
         }
     }
 }
 
 impl<S> ViewportState<S> {
+    /// Offsets for copying the buffer, including the shift
+    /// for content that is aligned within the area.
+    fn align_offsets(&self) -> (isize, isize) {
+        let h_offset = h_align_offset(
+            self.h_offset,
+            self.view_area.width,
+            self.area.width,
+            self.h_align,
+        );
+        let v_offset = v_align_offset(
+            self.v_offset,
+            self.view_area.height,
+            self.area.height,
+            self.v_align,
+        );
+        (h_offset, v_offset)
+    }
+
     /// Relocate mouse-events for use inside the viewport.
     pub fn relocate_crossterm(&self, event: &crossterm::event::Event) -> crossterm::event::Event {
         match event {
@@ -168,9 +277,11 @@ impl<S> ViewportState<S> {
             crossterm::event::Event::FocusLost => event.clone(),
             crossterm::event::Event::Key(_) => event.clone(),
             crossterm::event::Event::Mouse(m) => {
+                // the same offsets as the rendered content.
+                let (h_offset, v_offset) = self.align_offsets();
                 let mut m = *m;
-                m.column += self.h_offset as u16;
-                m.row += self.v_offset as u16;
+                m.column = (m.column as isize + h_offset).clamp(0, u16::MAX as isize) as u16;
+                m.row = (m.row as isize + v_offset).clamp(0, u16::MAX as isize) as u16;
                 crossterm::event::Event::Mouse(m)
             }
             crossterm::event::Event::Paste(_) => event.clone(),
@@ -205,26 +316,16 @@ impl<S> ScrollingState for ViewportState<S> {
     }
 
     fn set_vertical_offset(&mut self, offset: usize) -> bool {
+        // no overscroll, there is nothing to show beyond the buffer.
         let old_offset = self.v_offset;
-
-        if self.v_offset < self.view_area.height as usize {
-            self.v_offset = offset;
-        } else if self.v_offset >= self.view_area.height as usize {
-            self.v_offset = self.view_area.height.saturating_sub(1) as usize;
-        }
-
+        self.v_offset = min(offset, self.vertical_max_offset());
         old_offset != self.v_offset
     }
 
     fn set_horizontal_offset(&mut self, offset: usize) -> bool {
+        // no overscroll, there is nothing to show beyond the buffer.
         let old_offset = self.h_offset;
-
-        if self.h_offset < self.view_area.width as usize {
-            self.h_offset = offset;
-        } else if self.h_offset >= self.view_area.width as usize {
-            self.h_offset = self.view_area.width.saturating_sub(1) as usize;
-        }
-
+        self.h_offset = min(offset, self.horizontal_max_offset());
         old_offset != self.h_offset
     }
 }
@@ -243,3 +344,77 @@ where
         ScrollOutcome::Inner(r)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+    #[derive(Debug)]
+    struct Fill;
+
+    impl StatefulWidget for Fill {
+        type State = ();
+
+        fn render(self, area: Rect, buf: &mut Buffer, _state: &mut Self::State) {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    buf.get_mut(x, y).set_symbol("x");
+                }
+            }
+        }
+    }
+
+    fn mouse_down(column: u16, row: u16) -> Event {
+        Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn offset_clamped() {
+        let mut state = ViewportState::<()> {
+            area: Rect::new(0, 0, 10, 10),
+            view_area: Rect::new(0, 0, 12, 25),
+            ..Default::default()
+        };
+
+        assert!(state.set_vertical_offset(100));
+        assert_eq!(state.vertical_offset(), 15);
+        assert_eq!(state.vertical_offset(), state.vertical_max_offset());
+        assert!(!state.set_vertical_offset(15));
+
+        assert!(state.set_horizontal_offset(100));
+        assert_eq!(state.horizontal_offset(), 2);
+        assert_eq!(state.horizontal_offset(), state.horizontal_max_offset());
+    }
+
+    #[test]
+    fn relocate_centered() {
+        let area = Rect::new(0, 0, 10, 4);
+        let mut buf = Buffer::empty(area);
+        let mut state = ViewportState::<()>::default();
+
+        Viewport::new(Fill)
+            .view_size(Size::new(4, 2))
+            .content_align(Alignment::Center, VerticalAlignment::Center)
+            .render(area, &mut buf, &mut state);
+        assert_eq!(buf.get(2, 1).symbol(), " ");
+        assert_eq!(buf.get(3, 1).symbol(), "x");
+        assert_eq!(buf.get(6, 2).symbol(), "x");
+        assert_eq!(buf.get(7, 2).symbol(), " ");
+
+        // the first cell of the content is the origin of the view_area.
+        assert_eq!(
+            state.relocate_crossterm(&mouse_down(3, 1)),
+            mouse_down(0, 0)
+        );
+        assert_eq!(
+            state.relocate_crossterm(&mouse_down(6, 2)),
+            mouse_down(3, 1)
+        );
+    }
+}